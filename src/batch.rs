@@ -0,0 +1,137 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+//! Parsing many pages at once using multiple threads.
+//!
+//! All threads share a single configuration. Results are delivered in the same order as the input, regardless of which thread finishes first.
+
+use parse_wiki_text::Configuration;
+use std::{
+    collections::HashMap,
+    io::Write,
+    panic,
+    sync::{mpsc, Mutex},
+};
+
+/// Parses each page, given as a pair of title and wiki text, using `threads` worker threads and passes the result to `callback` in input order.
+///
+/// Each page is parsed with [`parse_with_options`](../fn.parse_with_options.html) using `options`. If `threads` is 0, the number of available CPUs is used.
+pub fn parse<'a>(
+    configuration: &Configuration,
+    options: &::ParseOptions,
+    threads: usize,
+    pages: impl IntoIterator<Item = (&'a str, &'a str)>,
+    mut callback: impl FnMut(Result<::Output<'a>, Vec<::Warning>>),
+) {
+    map_ordered(
+        threads,
+        pages,
+        |(title, wiki_text)| {
+            ::parse_with_options(
+                Some(title),
+                wiki_text,
+                &configuration.parse(wiki_text).nodes,
+                options,
+            )
+        },
+        |result| {
+            callback(result);
+            Ok(())
+        },
+    )
+    .unwrap_or_else(|error: ::std::convert::Infallible| match error {});
+}
+
+/// Parses each page, given as a pair of title and wiki text, using `threads` worker threads and writes the output as JSON to `writer`, one line per page, in input order.
+///
+/// Each page is parsed with [`parse_with_options`](../fn.parse_with_options.html) using `options`. When parsing fails in strict mode, the line is an object with the single field `fatal_warnings` containing the fatal warnings. If `threads` is 0, the number of available CPUs is used. Stops parsing at the first error and returns it.
+pub fn parse_json_lines<T: AsRef<str> + Send>(
+    configuration: &Configuration,
+    options: &::ParseOptions,
+    threads: usize,
+    pages: impl IntoIterator<Item = (T, T)>,
+    mut writer: impl Write,
+) -> ::std::io::Result<()> {
+    map_ordered(
+        threads,
+        pages,
+        |(title, wiki_text)| {
+            let wiki_text = wiki_text.as_ref();
+            match ::parse_with_options(
+                Some(title.as_ref()),
+                wiki_text,
+                &configuration.parse(wiki_text).nodes,
+                options,
+            ) {
+                Err(fatal_warnings) => ::serde_json::to_string(
+                    &::serde_json::json!({ "fatal_warnings": fatal_warnings }),
+                ),
+                Ok(output) => ::serde_json::to_string(&output),
+            }
+        },
+        |line| writeln!(writer, "{}", line?),
+    )
+}
+
+fn map_ordered<T: Send, U: Send, E>(
+    threads: usize,
+    items: impl IntoIterator<Item = T>,
+    function: impl Fn(T) -> U + Sync,
+    mut callback: impl FnMut(U) -> Result<(), E>,
+) -> Result<(), E> {
+    let threads = match threads {
+        0 => ::std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+        _ => threads,
+    };
+    // Limits the number of items being processed or waiting to be delivered, so that memory use doesn't grow with the number of items when the callback is slow or a single item takes long to process.
+    let limit = threads * 4;
+    let (input_sender, input_receiver) = mpsc::channel();
+    let input_receiver = Mutex::new(input_receiver);
+    ::std::thread::scope(|scope| {
+        let (output_sender, output_receiver) = mpsc::channel();
+        for _ in 0..threads {
+            let function = &function;
+            let input_receiver = &input_receiver;
+            let output_sender = output_sender.clone();
+            scope.spawn(move || loop {
+                let message = input_receiver.lock().unwrap().recv();
+                match message {
+                    Err(_) => break,
+                    Ok((index, item)) => {
+                        let value = panic::catch_unwind(panic::AssertUnwindSafe(|| function(item)));
+                        if output_sender.send((index, value)).is_err() {
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+        drop(output_sender);
+        let mut input_count = 0;
+        let mut output_count = 0;
+        let mut pending = HashMap::new();
+        let mut receive = |output_count: &mut usize| -> Result<(), E> {
+            let (index, value) = output_receiver.recv().unwrap();
+            // Propagates a panic in a worker thread to the calling thread instead of waiting forever for the item that was being processed.
+            pending.insert(index, value.unwrap_or_else(|error| panic::resume_unwind(error)));
+            while let Some(value) = pending.remove(output_count) {
+                callback(value)?;
+                *output_count += 1;
+            }
+            Ok(())
+        };
+        for item in items {
+            input_sender.send((input_count, item)).unwrap();
+            input_count += 1;
+            while input_count - output_count >= limit {
+                receive(&mut output_count)?;
+            }
+        }
+        drop(input_sender);
+        while output_count < input_count {
+            receive(&mut output_count)?;
+        }
+        Ok(())
+    })
+}
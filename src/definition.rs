@@ -2,198 +2,236 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
+use parse_wiki_text::Positioned;
+
 pub fn parse_definitions<'a>(
     context: &mut ::Context<'a>,
     heading_node: &::Node,
     nodes: &[::Node<'a>],
     output: &mut Option<Option<Vec<::Definition<'a>>>>,
 ) -> usize {
-    ::parse_ordered_list_section(
-        context,
-        heading_node,
-        nodes,
-        output,
-        |context, list_item| {
-            let mut definition = vec![];
-            let mut examples = vec![];
-            let mut iterator = list_item.nodes.iter();
-            let mut labels = None;
-            let mut phrase = None;
-            while let Some(node) = iterator.next() {
-                match node {
-                    ::Node::Italic { .. } => definition.push(::Flowing::Italic),
-                    ::Node::Link { target, text, .. } => {
-                        definition.push(::parse_link(context, node, target, text))
-                    }
-                    ::Node::Template {
-                        name, parameters, ..
-                    } => {
-                        if let Some(name) = ::parse_text(name) {
-                            match &name as _ {
-                                "Příznaky" => if definition.is_empty() {
-                                    if labels.is_some() {
-                                        labels = Some(None);
-                                        ::add_warning(context, node, ::WarningMessage::Duplicate);
-                                        continue;
-                                    }
-                                    if let [parameter @ ::Parameter { name: None, .. }] =
-                                        &parameters[..1]
-                                    {
-                                        if check_language(context, &parameter.value) {
-                                            labels =
-                                                Some(Some(::templates::parse_template_labels(
-                                                    context,
-                                                    node,
-                                                    &parameters[1..],
-                                                )));
-                                        } else {
-                                            labels = Some(None);
-                                            definition.push(::create_unknown2(
-                                                context,
-                                                node,
-                                                parameter,
-                                                ::WarningMessage::ValueConflicting,
-                                            ));
-                                        }
-                                        continue;
-                                    }
-                                    labels = Some(None)
-                                },
-                                "Upřesnění" => if let Some(result) =
-                                    ::templates::parse_template_qualifier(parameters)
-                                {
-                                    definition.push(result);
-                                    continue;
-                                },
-                                "Vazba" => if definition.is_empty() {
-                                    phrase = Some(if phrase.is_some() {
-                                        ::add_warning(context, node, ::WarningMessage::Duplicate);
-                                        None
-                                    } else {
-                                        match parameters.as_slice() {
-                                            [language_parameter @ ::Parameter {
-                                                name: None, ..
-                                            }, phrase_parameter @ ::Parameter {
-                                                name: None, ..
-                                            }] => if check_language(
-                                                context,
-                                                &language_parameter.value,
-                                            ) {
-                                                match ::parse_text_not_empty(
-                                                    &phrase_parameter.value,
-                                                ) {
-                                                    None => {
-                                                        definition.push(::create_unknown2(
-                                                            context,
-                                                            node,
-                                                            phrase_parameter,
-                                                            ::WarningMessage::ValueUnrecognized,
-                                                        ));
-                                                        None
-                                                    }
-                                                    value @ Some(_) => value,
-                                                }
-                                            } else {
-                                                definition.push(::create_unknown2(
-                                                    context,
-                                                    node,
-                                                    language_parameter,
-                                                    ::WarningMessage::ValueConflicting,
-                                                ));
-                                                None
-                                            },
-                                            _ => {
+    ::parse_ordered_list_section(context, heading_node, nodes, output, |context, list_item| {
+        let definition = parse_definition(context, list_item);
+        // Each definition in a section for word forms is expected to link to the lemma.
+        if context.form && ::form_of::find_lemma(&definition).is_none() {
+            ::add_warning(context, list_item, ::WarningCode::InvalidFormOf);
+        }
+        definition
+    })
+}
+
+#[allow(clippy::collapsible_match)]
+fn parse_definition<'a>(
+    context: &mut ::Context<'a>,
+    list_item: &::ListItem<'a>,
+) -> ::Definition<'a> {
+    let mut children = vec![];
+    let mut definition = vec![];
+    let mut examples = vec![];
+    let mut iterator = list_item.nodes.iter();
+    let mut labels = None;
+    let mut phrase = None;
+    let mut references = vec![];
+    while let Some(node) = iterator.next() {
+        match node {
+            ::Node::Italic { .. } => definition.push(::Flowing::Italic),
+            ::Node::Link { target, text, .. } => {
+                definition.push(::parse_link(context, node, target, text))
+            }
+            ::Node::Template {
+                name, parameters, ..
+            } => {
+                if let Some(name) = ::parse_text(name) {
+                    match &name as _ {
+                        "Příznaky" => if definition.is_empty() {
+                            if labels.is_some() {
+                                labels = Some(None);
+                                ::add_warning(context, node, ::WarningCode::DuplicateTemplate);
+                                continue;
+                            }
+                            if let [parameter @ ::Parameter { name: None, .. }] =
+                                &parameters[..1]
+                            {
+                                if check_language(context, &parameter.value) {
+                                    labels =
+                                        Some(Some(::templates::parse_template_labels(
+                                            context,
+                                            node,
+                                            &parameters[1..],
+                                        )));
+                                } else {
+                                    labels = Some(None);
+                                    definition.push(::create_unknown2(
+                                        context,
+                                        node,
+                                        parameter,
+                                        ::WarningCode::ConflictingLanguage,
+                                    ));
+                                }
+                                continue;
+                            }
+                            labels = Some(None)
+                        },
+                        "Upřesnění" => if let Some(result) =
+                            ::templates::parse_template_qualifier(parameters)
+                        {
+                            definition.push(result);
+                            continue;
+                        },
+                        "Vazba" => if definition.is_empty() {
+                            phrase = Some(if phrase.is_some() {
+                                ::add_warning(context, node, ::WarningCode::DuplicateTemplate);
+                                None
+                            } else {
+                                match parameters.as_slice() {
+                                    [language_parameter @ ::Parameter {
+                                        name: None, ..
+                                    }, phrase_parameter @ ::Parameter {
+                                        name: None, ..
+                                    }] => if check_language(
+                                        context,
+                                        &language_parameter.value,
+                                    ) {
+                                        match ::parse_text_not_empty(
+                                            &phrase_parameter.value,
+                                        ) {
+                                            None => {
                                                 definition.push(::create_unknown2(
                                                     context,
                                                     node,
-                                                    node,
-                                                    ::WarningMessage::ValueUnrecognized,
+                                                    phrase_parameter,
+                                                    ::WarningCode::InvalidParameterValue,
                                                 ));
                                                 None
                                             }
+                                            value @ Some(_) => value,
                                         }
-                                    });
-                                    continue;
-                                },
-                                _ => {}
-                            }
-                        }
-                        definition.push(::create_unknown(context, node));
-                    }
-                    ::Node::Text { value, .. } => if definition.is_empty() {
-                        let value = value.trim_left();
-                        if !value.is_empty() {
-                            definition.push(::Flowing::Text {
-                                value: ::Cow::Borrowed(value),
-                            });
-                        }
-                    } else {
-                        definition.push(::Flowing::Text {
-                            value: ::Cow::Borrowed(value),
-                        });
-                    },
-                    ::Node::UnorderedList { items, .. } => {
-                        examples = items
-                            .iter()
-                            .filter_map(|item| {
-                                let output_item = match item.nodes.as_slice() {
-                                    [::Node::Template {
-                                        name, parameters, ..
-                                    }] => parse_example(context, name, parameters),
-                                    [::Node::Template {
-                                        name: template_name,
-                                        parameters,
-                                        ..
-                                    }, tag_node]
-                                        if match tag_node {
-                                            ::Node::Tag { name: tag_name, .. } => tag_name == "ref",
-                                            _ => false,
-                                        } =>
-                                    {
-                                        ::add_warning(
+                                    } else {
+                                        definition.push(::create_unknown2(
+                                            context,
+                                            node,
+                                            language_parameter,
+                                            ::WarningCode::ConflictingLanguage,
+                                        ));
+                                        None
+                                    },
+                                    _ => {
+                                        definition.push(::create_unknown2(
                                             context,
-                                            tag_node,
-                                            ::WarningMessage::Supplementary,
-                                        );
-                                        parse_example(context, template_name, parameters)
+                                            node,
+                                            node,
+                                            ::WarningCode::InvalidTemplateParameters,
+                                        ));
+                                        None
                                     }
-                                    _ => None,
-                                };
-                                if output_item.is_none() {
-                                    ::add_warning(
-                                        context,
-                                        item,
-                                        ::WarningMessage::ValueUnrecognized,
-                                    );
                                 }
-                                output_item
-                            })
-                            .collect();
-                        while let Some(node) = iterator.next() {
-                            ::add_warning(context, node, ::WarningMessage::Unrecognized);
-                        }
+                            });
+                            continue;
+                        },
+                        _ => {}
                     }
-                    _ => definition.push(::create_unknown(context, node)),
                 }
+                definition.push(::create_unknown(context, node));
             }
-            if definition.is_empty() {
-                ::add_warning(context, list_item, ::WarningMessage::Empty);
+            ::Node::OrderedList { items, .. } => {
+                children.extend(items.iter().map(|item| parse_definition(context, item)))
             }
-            ::Definition {
-                phrase: phrase.unwrap_or_default(),
-                definition,
-                labels: labels.unwrap_or_default().unwrap_or_default(),
-                examples,
+            ::Node::Tag { name, .. } if name == "ref" => {
+                match context.reference_indices.get(&node.start()) {
+                    None => ::add_warning(context, node, ::WarningCode::UnresolvedReference),
+                    Some(index) => references.push(*index),
+                }
             }
-        },
-    )
+            ::Node::Text { value, .. } => if definition.is_empty() {
+                let value = value.trim_start();
+                if !value.is_empty() {
+                    definition.push(::Flowing::Text {
+                        value: ::Cow::Borrowed(value),
+                    });
+                }
+            } else {
+                definition.push(::Flowing::Text {
+                    value: ::Cow::Borrowed(value),
+                });
+            },
+            ::Node::UnorderedList { items, .. } => {
+                examples = items
+                    .iter()
+                    .filter_map(|item| {
+                        let output_item = match item.nodes.as_slice() {
+                            [::Node::Template {
+                                name, parameters, ..
+                            }] => parse_example(context, name, parameters),
+                            [::Node::Template {
+                                name: template_name,
+                                parameters,
+                                ..
+                            }, tag_node]
+                                if match tag_node {
+                                    ::Node::Tag { name: tag_name, .. } => tag_name == "ref",
+                                    _ => false,
+                                } =>
+                            {
+                                parse_example(context, template_name, parameters).map(
+                                    |mut example| {
+                                        match context
+                                            .reference_indices
+                                            .get(&tag_node.start())
+                                        {
+                                            None => ::add_warning(
+                                                context,
+                                                tag_node,
+                                                ::WarningCode::UnresolvedReference,
+                                            ),
+                                            Some(index) => {
+                                                example.references.push(*index)
+                                            }
+                                        }
+                                        example
+                                    },
+                                )
+                            }
+                            _ => None,
+                        };
+                        if output_item.is_none() {
+                            ::add_warning(
+                                context,
+                                item,
+                                ::WarningCode::InvalidExample,
+                            );
+                        }
+                        output_item
+                    })
+                    .collect();
+                for node in iterator.by_ref() {
+                    match node {
+                        ::Node::OrderedList { items, .. } => children
+                            .extend(items.iter().map(|item| parse_definition(context, item))),
+                        _ => ::add_warning(context, node, ::WarningCode::UnrecognizedElement),
+                    }
+                }
+            }
+            _ => definition.push(::create_unknown(context, node)),
+        }
+    }
+    if definition.is_empty() && children.is_empty() {
+        ::add_warning(context, list_item, ::WarningCode::EmptyDefinition);
+    }
+    ::Definition {
+        children,
+        phrase: phrase.unwrap_or_default(),
+        definition,
+        labels: labels.unwrap_or_default().unwrap_or_default(),
+        examples,
+        references,
+    }
 }
 
 fn parse_example<'a>(
     context: &mut ::Context,
     name: &[::Node<'a>],
     parameters: &[::Parameter<'a>],
-) -> Option<::Cow<'a, str>> {
+) -> Option<::Example<'a>> {
     if ::text_equals(name, "Příklad") {
         if let [::Parameter {
             name: None,
@@ -206,7 +244,10 @@ fn parse_example<'a>(
         }] = parameters
         {
             if check_language(context, language) {
-                return ::parse_text(example);
+                return ::parse_text(example).map(|example| ::Example {
+                    example,
+                    references: vec![],
+                });
             }
         }
     }
@@ -214,5 +255,9 @@ fn parse_example<'a>(
 }
 
 fn check_language(context: &::Context, nodes: &[::Node]) -> bool {
-    ::text_equals(nodes, context.language.unwrap().language_code())
+    // The language code of a language neither known by the parser nor registered can't be checked.
+    match context.language.as_ref().and_then(::EntryLanguage::language_code) {
+        None => true,
+        Some(language_code) => ::text_equals(nodes, language_code),
+    }
 }
@@ -9,14 +9,14 @@ pub fn parse_details<'a>(
     output: &mut Option<Vec<Vec<::Flowing<'a>>>>,
 ) {
     *output = Some(if output.is_some() {
-        ::add_warning(context, list_node, ::WarningMessage::Duplicate);
+        ::add_warning(context, list_node, ::WarningCode::DuplicateList);
         vec![]
     } else {
         list_items
             .iter()
             .filter_map(|item| {
                 if item.nodes.is_empty() {
-                    ::add_warning(context, item, ::WarningMessage::Empty);
+                    ::add_warning(context, item, ::WarningCode::EmptyListItem);
                     None
                 } else {
                     Some(
@@ -39,3 +39,150 @@ pub fn parse_details<'a>(
             .collect()
     });
 }
+
+#[derive(Default)]
+pub struct Grammar<'a> {
+    pub animacy: Option<::Animacy>,
+    pub aspect: Option<::Aspect>,
+    pub aspect_counterparts: Vec<::Cow<'a, str>>,
+    pub gender: Option<::Gender>,
+    pub transitivity: Option<::Transitivity>,
+}
+
+pub fn parse_grammar<'a>(
+    context: &mut ::Context<'a>,
+    list_items: &[::ListItem<'a>],
+    output: &mut Grammar<'a>,
+) {
+    for item in list_items {
+        if item.nodes.is_empty() {
+            continue;
+        }
+        if let Some(counterparts) = parse_aspect_counterparts(&item.nodes) {
+            output.aspect_counterparts.extend(counterparts);
+            continue;
+        }
+        // A line with markup other than links is not a list of grammatical properties. It's only kept in the details.
+        let words = match collect_words(&item.nodes) {
+            None => continue,
+            Some(words) => words,
+        };
+        let mut grammar = Grammar::default();
+        let mut aspects = vec![];
+        let mut recognized = true;
+        for word in &words {
+            // The words `rod` and `vid` name the category of the following values, and the conjunctions join values.
+            match word.as_str() {
+                "a" | "i" | "rod" | "vid" => continue,
+                _ => {}
+            }
+            if word.starts_with("mužsk") {
+                grammar.gender = Some(::Gender::Masculine);
+            } else if word.starts_with("žensk") {
+                grammar.gender = Some(::Gender::Feminine);
+            } else if word.starts_with("středn") {
+                grammar.gender = Some(::Gender::Neuter);
+            } else if word.starts_with("neživotn") {
+                grammar.animacy = Some(::Animacy::Inanimate);
+            } else if word.starts_with("životn") {
+                grammar.animacy = Some(::Animacy::Animate);
+            } else if word.starts_with("nedokonav") {
+                aspects.push(::Aspect::Imperfective);
+            } else if word.starts_with("dokonav") {
+                aspects.push(::Aspect::Perfective);
+            } else if word.starts_with("obouvid") {
+                aspects.push(::Aspect::Biaspectual);
+            } else if word.starts_with("nepřechodn") {
+                grammar.transitivity = Some(::Transitivity::Intransitive);
+            } else if word.starts_with("přechodn") {
+                grammar.transitivity = Some(::Transitivity::Transitive);
+            } else {
+                recognized = false;
+                break;
+            }
+        }
+        if !recognized {
+            // A line starting with the name of a category is meant to give its value, but lines without one may be free text notes.
+            if let Some("rod") | Some("vid") = words.first().map(String::as_str) {
+                ::add_warning(context, item, ::WarningCode::UnrecognizedDetail);
+            }
+            continue;
+        }
+        grammar.aspect = match aspects.as_slice() {
+            [] => None,
+            [aspect] => Some(*aspect),
+            _ => Some(::Aspect::Biaspectual),
+        };
+        set_value(context, item, &mut output.animacy, grammar.animacy);
+        set_value(context, item, &mut output.aspect, grammar.aspect);
+        set_value(context, item, &mut output.gender, grammar.gender);
+        set_value(context, item, &mut output.transitivity, grammar.transitivity);
+    }
+}
+
+// Parses a line such as `vidový protějšek: [[udělat]]`.
+fn parse_aspect_counterparts<'a>(nodes: &[::Node<'a>]) -> Option<Vec<::Cow<'a, str>>> {
+    let link_index = nodes
+        .iter()
+        .position(|node| matches!(node, ::Node::Link { .. }))?;
+    match collect_words(&nodes[..link_index])?.as_slice() {
+        [word1, word2] if word1.starts_with("vidov") && word2.starts_with("protějš") => {}
+        _ => return None,
+    }
+    let mut counterparts = vec![];
+    for node in &nodes[link_index..] {
+        match node {
+            ::Node::Italic { .. } => {}
+            ::Node::Link { target, .. } => counterparts.push(::Cow::Borrowed(*target)),
+            ::Node::Text { value, .. } => if !value
+                .split(|character: char| !character.is_alphanumeric())
+                .all(|word| word.is_empty() || word == "a" || word == "i" || word == "nebo")
+            {
+                return None;
+            },
+            _ => return None,
+        }
+    }
+    Some(counterparts)
+}
+
+fn collect_words(nodes: &[::Node]) -> Option<Vec<String>> {
+    let mut texts = vec![];
+    for node in nodes {
+        match node {
+            ::Node::Italic { .. } => {}
+            ::Node::Link { text, .. } => for node in text {
+                match node {
+                    ::Node::Text { value, .. } => texts.push(*value),
+                    _ => return None,
+                }
+            },
+            ::Node::Text { value, .. } => texts.push(*value),
+            _ => return None,
+        }
+    }
+    Some(
+        texts
+            .iter()
+            .flat_map(|text| text.split(|character: char| !character.is_alphanumeric()))
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect(),
+    )
+}
+
+fn set_value<T: PartialEq>(
+    context: &mut ::Context,
+    item: &::ListItem,
+    output: &mut Option<T>,
+    value: Option<T>,
+) {
+    if let Some(value) = value {
+        match output {
+            Some(previous) if *previous != value => {
+                ::add_warning(context, item, ::WarningCode::ConflictingDetails)
+            }
+            _ => *output = Some(value),
+        }
+    }
+}
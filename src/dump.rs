@@ -0,0 +1,251 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+//! Reading pages from MediaWiki XML dumps of the Czech language edition of Wiktionary.
+//!
+//! Dumps are published as `cswiktionary-latest-pages-articles.xml.bz2`. Reading the compressed file directly requires the feature `bzip2`.
+
+use quick_xml::events::Event;
+use std::{fmt, fs::File, io::BufRead, io::BufReader, path::Path};
+
+/// Error from reading a dump.
+#[derive(Debug)]
+pub enum Error {
+    /// The dump is compressed with bzip2, but the feature `bzip2` is not enabled.
+    Bzip2Disabled,
+
+    /// The dump is well-formed XML, but doesn't have the structure of a MediaWiki dump.
+    Format(&'static str),
+
+    /// The dump could not be read.
+    Io(::std::io::Error),
+
+    /// The dump is not well-formed XML.
+    Xml(::quick_xml::Error),
+}
+
+/// A single page from a dump.
+#[derive(Clone, Debug)]
+pub struct Page {
+    /// The number of the namespace of the page. The main namespace, containing the dictionary entries, has the number 0.
+    pub namespace: i32,
+
+    /// The identifier of the revision of the page contained in the dump.
+    pub revision_id: u64,
+
+    /// The wiki text of the page.
+    pub text: String,
+
+    /// The title of the page, including the namespace prefix, if any.
+    pub title: String,
+}
+
+/// Iterator over the pages in a dump.
+///
+/// Iteration stops after the first error.
+pub struct Pages<R> {
+    buffer: Vec<u8>,
+    finished: bool,
+    reader: ::quick_xml::Reader<R>,
+}
+
+#[derive(Default)]
+struct PageBuilder {
+    namespace: Option<String>,
+    revision_id: Option<String>,
+    text: Option<String>,
+    title: Option<String>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Bzip2Disabled => formatter.write_str("bzip2 support is not enabled"),
+            Error::Format(message) => write!(formatter, "invalid dump: {}", message),
+            Error::Io(error) => error.fmt(formatter),
+            Error::Xml(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+impl From<::std::io::Error> for Error {
+    fn from(error: ::std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<::quick_xml::Error> for Error {
+    fn from(error: ::quick_xml::Error) -> Self {
+        Error::Xml(error)
+    }
+}
+
+impl Page {
+    /// Parses the wiki text of the page with the given configuration, checking it against the title of the page.
+    ///
+    /// The configuration should be the one returned by [`create_configuration`](../fn.create_configuration.html), reused for all pages.
+    #[must_use]
+    pub fn parse<'a>(&'a self, configuration: &::parse_wiki_text::Configuration) -> ::Output<'a> {
+        ::parse_page(&self.title, &self.text, &configuration.parse(&self.text).nodes)
+    }
+}
+
+impl<R: BufRead> Pages<R> {
+    /// Reads pages from uncompressed XML.
+    pub fn new(reader: R) -> Self {
+        Pages {
+            buffer: vec![],
+            finished: false,
+            reader: ::quick_xml::Reader::from_reader(reader),
+        }
+    }
+
+    #[allow(clippy::collapsible_match)]
+    fn read_page(&mut self) -> Result<Option<Page>, Error> {
+        let mut page = None;
+        let mut path = vec![];
+        loop {
+            self.buffer.clear();
+            match self.reader.read_event_into(&mut self.buffer)? {
+                Event::CData(text) => if let Some(field) = get_field(&mut page, &path) {
+                    field.push_str(&String::from_utf8_lossy(&text));
+                },
+                Event::Empty(element) => if page.is_some() {
+                    let mut path = path.clone();
+                    path.push(element.name().as_ref().to_vec());
+                    if let Some(field) = get_field(&mut page, &path) {
+                        field.clear();
+                    }
+                },
+                Event::End(_) => if path.pop().is_none() {
+                    if let Some(page) = page {
+                        return page.build().map(Some);
+                    }
+                },
+                Event::Eof => {
+                    return match page {
+                        None => Ok(None),
+                        Some(_) => Err(Error::Format("unexpected end of dump")),
+                    }
+                }
+                Event::Start(element) => match page {
+                    None => if element.name().as_ref() == b"page" {
+                        page = Some(PageBuilder::default());
+                    },
+                    Some(_) => path.push(element.name().as_ref().to_vec()),
+                },
+                Event::Text(text) => if let Some(field) = get_field(&mut page, &path) {
+                    field.push_str(&text.unescape()?);
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bzip2")]
+impl<R: BufRead> Pages<BufReader<::bzip2::read::MultiBzDecoder<R>>> {
+    /// Reads pages from XML compressed with bzip2, including multistream files.
+    pub fn new_bzip2(reader: R) -> Self {
+        Pages::new(BufReader::new(::bzip2::read::MultiBzDecoder::new(reader)))
+    }
+}
+
+impl<R: BufRead> Iterator for Pages<R> {
+    type Item = Result<Page, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.read_page();
+        if let Ok(None) | Err(_) = result {
+            self.finished = true;
+        }
+        result.transpose()
+    }
+}
+
+impl PageBuilder {
+    fn build(self) -> Result<Page, Error> {
+        Ok(Page {
+            namespace: self
+                .namespace
+                .ok_or(Error::Format("page without namespace"))?
+                .trim()
+                .parse()
+                .map_err(|_| Error::Format("invalid namespace"))?,
+            revision_id: self
+                .revision_id
+                .ok_or(Error::Format("page without revision"))?
+                .trim()
+                .parse()
+                .map_err(|_| Error::Format("invalid revision id"))?,
+            text: self.text.ok_or(Error::Format("page without text"))?,
+            title: self.title.ok_or(Error::Format("page without title"))?,
+        })
+    }
+}
+
+/// Opens a dump file, decompressing it if the file name ends with `.bz2`.
+pub fn open(path: impl AsRef<Path>) -> Result<Pages<Box<dyn BufRead>>, Error> {
+    let path = path.as_ref();
+    let file = BufReader::new(File::open(path)?);
+    Ok(Pages::new(
+        if path.extension().and_then(|extension| extension.to_str()) == Some("bz2") {
+            open_bzip2(file)?
+        } else {
+            Box::new(file)
+        },
+    ))
+}
+
+/// Parses each page in the main namespace of a dump and passes it together with its output to `callback`.
+///
+/// A single configuration from [`create_configuration`](../fn.create_configuration.html) is used for all pages. Pages in other namespaces are skipped. Stops at the first error reading the dump.
+pub fn parse_pages<R: BufRead>(
+    pages: Pages<R>,
+    mut callback: impl for<'a> FnMut(&'a Page, ::Output<'a>),
+) -> Result<(), Error> {
+    let configuration = ::create_configuration();
+    for page in pages {
+        let page = page?;
+        if page.namespace == 0 {
+            callback(&page, page.parse(&configuration));
+        }
+    }
+    Ok(())
+}
+
+fn get_field<'a>(page: &'a mut Option<PageBuilder>, path: &[Vec<u8>]) -> Option<&'a mut String> {
+    let page = page.as_mut()?;
+    let field = match path {
+        [name] => match name.as_slice() {
+            b"ns" => &mut page.namespace,
+            b"title" => &mut page.title,
+            _ => return None,
+        },
+        [revision, name] if revision == b"revision" => match name.as_slice() {
+            b"id" => &mut page.revision_id,
+            b"text" => &mut page.text,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(field.get_or_insert_with(String::new))
+}
+
+#[cfg(feature = "bzip2")]
+fn open_bzip2(file: BufReader<File>) -> Result<Box<dyn BufRead>, Error> {
+    Ok(Box::new(BufReader::new(
+        ::bzip2::read::MultiBzDecoder::new(file),
+    )))
+}
+
+#[cfg(not(feature = "bzip2"))]
+fn open_bzip2(_file: BufReader<File>) -> Result<Box<dyn BufRead>, Error> {
+    Err(Error::Bzip2Disabled)
+}
@@ -24,7 +24,7 @@ pub fn parse_etymology<'a>(
             node_index += 1;
         }
         if output_nodes.is_empty() {
-            ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+            ::add_warning(context, heading_node, ::WarningCode::EmptySection);
         }
         *output = Some(Some(output_nodes));
         node_index
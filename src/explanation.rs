@@ -0,0 +1,256 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+impl ::Warning {
+    /// Returns a short explanation of the warning and a suggested fix, for editors of the wiki text.
+    #[must_use]
+    pub fn explanation(&self, locale: ::Locale) -> ::Explanation {
+        self.code.explanation(locale)
+    }
+}
+
+impl ::WarningCode {
+    /// Returns a short explanation of warnings with this code and a suggested fix, for editors of the wiki text.
+    #[must_use]
+    pub fn explanation(self, locale: ::Locale) -> ::Explanation {
+        let (description_cs, fix_cs, description_en, fix_en) = texts(self);
+        match locale {
+            ::Locale::Cs => ::Explanation {
+                description: description_cs,
+                fix: fix_cs,
+            },
+            ::Locale::En => ::Explanation {
+                description: description_en,
+                fix: fix_en,
+            },
+        }
+    }
+}
+
+fn texts(code: ::WarningCode) -> (&'static str, &'static str, &'static str, &'static str) {
+    match code {
+        ::WarningCode::ConflictingDetails => (
+            "Údaje o slovním druhu uvádějí pro stejnou mluvnickou kategorii více hodnot, například dva různé rody.",
+            "Ponechte jen správnou hodnotu, nebo slovo rozdělte do samostatných oddílů slovního druhu.",
+            "The details of the part of speech give more than one value for the same grammatical category, such as two different genders.",
+            "Keep only the correct value, or split the word into separate sections for the part of speech.",
+        ),
+        ::WarningCode::ConflictingLanguage => (
+            "Jazyk uvedený v parametru šablony neodpovídá jazyku oddílu, ve kterém šablona je.",
+            "Opravte kód jazyka v šabloně na kód jazyka oddílu, nebo obsah přesuňte do oddílu správného jazyka.",
+            "The language given as a parameter of the template is not the language of the section the template is in.",
+            "Change the language code in the template to the code of the language of the section, or move the content to the section for the right language.",
+        ),
+        ::WarningCode::DuplicateLanguageSection => (
+            "Stránka obsahuje více oddílů pro stejný jazyk.",
+            "Slučte oddíly do jednoho.",
+            "The page has more than one section for the same language.",
+            "Merge the sections into one.",
+        ),
+        ::WarningCode::DuplicateList => (
+            "Oddíl obsahuje více seznamů, ačkoli je očekáván jen jeden. Obsah oddílu je vynechán.",
+            "Spojte seznamy do jednoho a odstraňte prázdné řádky mezi položkami.",
+            "The section contains more than one list where only one is expected. The content of the section is left out.",
+            "Join the lists into one, and remove empty lines between the list items.",
+        ),
+        ::WarningCode::DuplicateParameter => (
+            "Šablona má více parametrů se stejným významem.",
+            "Odstraňte opakovaný parametr, nebo jeho hodnotu slučte s prvním.",
+            "The template has more than one parameter with the same meaning.",
+            "Remove the repeated parameter, or merge its value into the first one.",
+        ),
+        ::WarningCode::DuplicateSection => (
+            "Oddíl má stejný nadpis jako předchozí oddíl na stejné úrovni. Jeho obsah je vynechán.",
+            "Slučte oddíl s předchozím oddílem se stejným nadpisem.",
+            "The section has the same heading as a previous section at the same level. Its content is left out.",
+            "Merge the section into the previous section with the same heading.",
+        ),
+        ::WarningCode::DuplicateTemplate => (
+            "Šablona smí být na tomto místě jen jednou, ale vyskytuje se vícekrát.",
+            "Ponechte jen jednu šablonu a obsah ostatních do ní slučte.",
+            "The template may occur only once in this position, but occurs more than once.",
+            "Keep one of the templates and merge the content of the others into it.",
+        ),
+        ::WarningCode::DuplicateValue => (
+            "Šablona má stejnou hodnotu ve více parametrech.",
+            "Odstraňte opakovanou hodnotu.",
+            "The template has the same value in more than one parameter.",
+            "Remove the repeated value.",
+        ),
+        ::WarningCode::EmptyDefinition => (
+            "Položka seznamu v oddílu význam neobsahuje text výkladu.",
+            "Doplňte výklad, nebo prázdnou položku odstraňte.",
+            "The list item in the section význam has no definition text.",
+            "Write the definition, or remove the empty list item.",
+        ),
+        ::WarningCode::EmptyListItem => (
+            "Položka seznamu je prázdná.",
+            "Doplňte položku, nebo ji odstraňte.",
+            "The list item has no content.",
+            "Fill in the list item, or remove it.",
+        ),
+        ::WarningCode::EmptyParameter => (
+            "Parametr by měl obsahovat tvary oddělené čárkami nebo lomítky, ale neobsahuje žádný.",
+            "Doplňte tvary, nebo parametr odstraňte.",
+            "The parameter should contain forms separated by commas or slashes, but contains none.",
+            "Fill in the forms, or remove the parameter.",
+        ),
+        ::WarningCode::EmptySection => (
+            "Oddíl nemá žádný obsah.",
+            "Doplňte oddíl, nebo ho odstraňte. Chybějící obsah lze označit šablonou Doplnit.",
+            "The section has no content.",
+            "Fill in the section, or remove it. Missing content can be marked with the template Doplnit.",
+        ),
+        ::WarningCode::EmptyTemplate => (
+            "Šablona nemá žádné vyplněné parametry.",
+            "Vyplňte parametry šablony, nebo ji odstraňte.",
+            "The template has no parameters with content.",
+            "Fill in the parameters of the template, or remove it.",
+        ),
+        ::WarningCode::HyphenationTitleMismatch => (
+            "Dělení neobsahuje stejná písmena jako název stránky.",
+            "Opravte dělení tak, aby se od názvu stránky lišilo jen přidanými dělicími značkami.",
+            "The hyphenation doesn't contain the same letters as the title of the page.",
+            "Correct the hyphenation so that it differs from the title of the page only by the added hyphenation marks.",
+        ),
+        ::WarningCode::IncompleteEntry => (
+            "Šablona Doplnit označuje heslo jako neúplné. Nejde o chybu.",
+            "Doplňte chybějící údaje a šablonu odstraňte.",
+            "The template Doplnit marks the entry as incomplete. This is not an error.",
+            "Fill in the missing information and remove the template.",
+        ),
+        ::WarningCode::InflectionTitleMismatch => (
+            "Žádný ze základních tvarů v tabulce ohýbání není název stránky.",
+            "Ověřte, že tabulka patří k této stránce, a opravte základní tvar.",
+            "None of the basic forms in the inflection table is the title of the page.",
+            "Check that the table belongs to this page, and correct the basic form.",
+        ),
+        ::WarningCode::InvalidExample => (
+            "Položka seznamu příkladů není jediná šablona Příklad.",
+            "Zapište příklad šablonou Příklad, případně následovanou referencí.",
+            "The item in the list of examples is not a single template Příklad.",
+            "Write the example with the template Příklad, optionally followed by a reference.",
+        ),
+        ::WarningCode::InvalidExternalLink => (
+            "Položka v oddílu externí odkazy není žádná z rozpoznaných šablon odkazů.",
+            "Zapište odkaz jednou ze šablon pro sesterské projekty, například Wikipedie nebo Commons, s jedním parametrem s názvem podle druhu cíle.",
+            "The item in the section externí odkazy is not one of the recognized link templates.",
+            "Write the link with one of the templates for sister projects, such as Wikipedie or Commons, with one parameter named after the kind of target.",
+        ),
+        ::WarningCode::InvalidFormOf => (
+            "Výklad v oddílu tvaru slova neodkazuje na základní tvar.",
+            "Zapište výklad jako popis tvaru s odkazem na základní tvar, například: genitiv jednotného čísla substantiva [[pes]].",
+            "The definition in the section for word forms doesn't link to the lemma.",
+            "Write the definition as a description of the form with a link to the lemma, for example: genitiv jednotného čísla substantiva [[pes]].",
+        ),
+        ::WarningCode::InvalidHyphenation => (
+            "Oddíl dělení neobsahuje jedinou položku seznamu s prostým textem.",
+            "Zapište dělení jako jedinou položku seznamu bez šablon a odkazů.",
+            "The section dělení doesn't contain a single list item with plain text.",
+            "Write the hyphenation as a single list item without templates and links.",
+        ),
+        ::WarningCode::InvalidParameterValue => (
+            "Parametr šablony neobsahuje prostý text, ačkoli ho obsahovat má.",
+            "Vyplňte parametr prostým textem bez šablon, odkazů a formátování.",
+            "The template parameter doesn't contain plain text where plain text is expected.",
+            "Fill in the parameter with plain text without templates, links and formatting.",
+        ),
+        ::WarningCode::InvalidPronunciation => (
+            "Položka v oddílu výslovnost nemá rozpoznanou kombinaci šablon.",
+            "Zapište výslovnost šablonou IPA, případně spolu se šablonou Audio.",
+            "The item in the section výslovnost doesn't have a recognized combination of templates.",
+            "Write the pronunciation with the template IPA, optionally together with the template Audio.",
+        ),
+        ::WarningCode::InvalidReferences => (
+            "Značka references má obsah.",
+            "Přesuňte reference do textu stránky a značku zapište jako prázdnou: <references/>.",
+            "The tag references has content.",
+            "Move the references into the text of the page, and write the tag as empty: <references/>.",
+        ),
+        ::WarningCode::InvalidRelatedTerm => (
+            "Položka seznamu souvisejících výrazů není jediný odkaz.",
+            "Zapište každý výraz jako samostatnou položku seznamu obsahující jen odkaz na něj.",
+            "The item in the list of related terms is not a single link.",
+            "Write each term as a separate list item containing only a link to it.",
+        ),
+        ::WarningCode::InvalidTemplateParameters => (
+            "Šablona nemá očekávaný počet nebo kombinaci parametrů.",
+            "Opravte parametry podle dokumentace šablony.",
+            "The template doesn't have the expected number or combination of parameters.",
+            "Correct the parameters according to the documentation of the template.",
+        ),
+        ::WarningCode::InvalidTranslation => (
+            "Položka v oddílu překlady není jediná šablona Překlady.",
+            "Zapište překlady pro každý význam šablonou Překlady jako samostatnou položku číslovaného seznamu.",
+            "The item in the section překlady is not a single template Překlady.",
+            "Write the translations for each definition with the template Překlady as a separate item of a numbered list.",
+        ),
+        ::WarningCode::MissingDefinitions => (
+            "Oddíl slovního druhu nemá oddíl význam.",
+            "Doplňte oddíl význam s výklady.",
+            "The section for the part of speech has no section význam.",
+            "Add the section význam with the definitions.",
+        ),
+        ::WarningCode::MissingParameter => (
+            "Šabloně chybí povinný parametr.",
+            "Doplňte chybějící parametr, například základní tvar.",
+            "The template lacks a required parameter.",
+            "Add the missing parameter, such as the basic form.",
+        ),
+        ::WarningCode::MissingPosSection => (
+            "Oddíl jazyka nemá žádný oddíl slovního druhu.",
+            "Doplňte oddíl slovního druhu, například podstatné jméno, s výklady.",
+            "The section for the language has no section for a part of speech.",
+            "Add a section for a part of speech, such as podstatné jméno, with the definitions.",
+        ),
+        ::WarningCode::UnknownLanguage => (
+            "Nadpis oddílu jazyka není název známého jazyka. Oddíl je zpracován, ale jazyk hesla není určen.",
+            "Opravte nadpis na název jazyka podle seznamu jazyků, nebo jazyk zaregistrujte v nastavení parseru.",
+            "The heading of the section for the language is not the name of a known language. The section is parsed, but the language of the entry is not identified.",
+            "Correct the heading to the name of a language from the list of languages, or register the language in the options of the parser.",
+        ),
+        ::WarningCode::UnrecognizedDetail => (
+            "Řádek v údajích o slovním druhu začíná slovem rod nebo vid, ale jeho hodnoty nejsou rozpoznány. Je zachován jen jako volný text.",
+            "Zapište údaj podle vzoru hesla, například: rod mužský životný, vid nedokonavý.",
+            "The line in the details of the part of speech starts with rod or vid, but its values are not recognized. It is only kept as free text.",
+            "Write the detail according to the entry layout guidelines, for example: rod mužský životný, vid nedokonavý.",
+        ),
+        ::WarningCode::UnrecognizedElement => (
+            "Prvek na tomto místě není rozpoznán a jeho obsah je vynechán.",
+            "Ověřte, že prvek patří do tohoto oddílu, a zapište ho podle vzoru hesla.",
+            "The element is not recognized in this position, and its content is left out.",
+            "Check that the element belongs in this section, and write it according to the entry layout guidelines.",
+        ),
+        ::WarningCode::UnrecognizedHeading => (
+            "Nadpis oddílu na tomto místě není rozpoznán a obsah oddílu je vynechán.",
+            "Opravte nadpis na některý z nadpisů podle vzoru hesla, nebo oddíl přesuňte na správnou úroveň.",
+            "The heading of the section is not recognized in this position, and the content of the section is left out.",
+            "Change the heading to one of the headings of the entry layout guidelines, or move the section to the right level.",
+        ),
+        ::WarningCode::UnrecognizedInflectionForms => (
+            "Parametr tabulky ohýbání neobsahuje tvary oddělené čárkami nebo lomítky.",
+            "Zapište tvary jako prostý text nebo odkazy oddělené čárkami nebo lomítky.",
+            "The parameter of the inflection table doesn't contain forms separated by commas or slashes.",
+            "Write the forms as plain text or links separated by commas or slashes.",
+        ),
+        ::WarningCode::UnrecognizedParameter => (
+            "Šablona má parametr s nerozpoznaným názvem.",
+            "Opravte název parametru podle dokumentace šablony, nebo parametr odstraňte.",
+            "The template has a parameter with a name that is not recognized.",
+            "Correct the name of the parameter according to the documentation of the template, or remove the parameter.",
+        ),
+        ::WarningCode::UnrecognizedTemplate => (
+            "Šablona na tomto místě není rozpoznána a její obsah je vynechán.",
+            "Ověřte, že šablona patří do tohoto oddílu, nebo ji nahraďte šablonou podle vzoru hesla.",
+            "The template is not recognized in this position, and its content is left out.",
+            "Check that the template belongs in this section, or replace it with a template from the entry layout guidelines.",
+        ),
+        ::WarningCode::UnresolvedReference => (
+            "Značka ref odkazuje jménem na referenci, která na stránce není.",
+            "Opravte jméno reference, nebo doplňte obsah reference.",
+            "The tag ref refers by name to a reference that is not on the page.",
+            "Correct the name of the reference, or fill in the content of the reference.",
+        ),
+    }
+}
@@ -74,7 +74,7 @@ pub fn parse_external_links<'a>(
                             None
                         };
                         if result.is_none() {
-                            ::add_warning(context, item, ::WarningMessage::ValueUnrecognized);
+                            ::add_warning(context, item, ::WarningCode::InvalidExternalLink);
                         }
                         result
                     })
@@ -91,7 +91,7 @@ fn parse_template<'a>(
 ) -> Option<::ExternalLink<'a>> {
     if let [parameter] = parameters {
         if let Some(kind) = ::parse_parameter_name(parameter) {
-            if kinds.iter().any(|item| kind == *item) {
+            if kinds.contains(&kind) {
                 if let Some(value) = ::parse_text(&parameter.value) {
                     return Some(::ExternalLink {
                         kind: ::Cow::Borrowed(kind),
@@ -0,0 +1,109 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// The lemma is the target of the last link in the definition.
+pub fn find_lemma<'a, 'b>(
+    definition: &'b ::Definition<'a>,
+) -> Option<(usize, &'b ::Cow<'a, str>)> {
+    definition
+        .definition
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, node)| match node {
+            ::Flowing::Link { target, .. } => Some((index, target)),
+            _ => None,
+        })
+}
+
+pub fn parse_form_of<'a>(definitions: &[::Definition<'a>]) -> Vec<::FormOf<'a>> {
+    definitions
+        .iter()
+        .filter_map(|definition| {
+            let (lemma_index, lemma) = find_lemma(definition)?;
+            let mut words = vec![];
+            for node in &definition.definition[0..lemma_index] {
+                let text = match node {
+                    ::Flowing::Link { text, .. } => text,
+                    ::Flowing::Text { value } => value,
+                    _ => continue,
+                };
+                words.extend(
+                    text.split(|character: char| !character.is_alphanumeric())
+                        .filter(|word| !word.is_empty())
+                        .map(str::to_lowercase),
+                );
+            }
+            let mut tags = vec![];
+            for (index, word) in words.iter().enumerate() {
+                let tag = match words.get(index + 1) {
+                    Some(next) if next.starts_with("osob") => match word.as_str() {
+                        "1" | "první" => Some(::GrammaticalTag::FirstPerson),
+                        "2" | "druhá" | "druhé" | "druhý" => Some(::GrammaticalTag::SecondPerson),
+                        "3" | "třetí" => Some(::GrammaticalTag::ThirdPerson),
+                        _ => None,
+                    },
+                    Some(next) if next.starts_with("pád") => match word.as_str() {
+                        "1" => Some(::GrammaticalTag::Nominative),
+                        "2" => Some(::GrammaticalTag::Genitive),
+                        "3" => Some(::GrammaticalTag::Dative),
+                        "4" => Some(::GrammaticalTag::Accusative),
+                        "5" => Some(::GrammaticalTag::Vocative),
+                        "6" => Some(::GrammaticalTag::Locative),
+                        "7" => Some(::GrammaticalTag::Instrumental),
+                        _ => None,
+                    },
+                    _ => parse_tag(word),
+                };
+                if let Some(tag) = tag {
+                    if !tags.contains(&tag) {
+                        tags.push(tag);
+                    }
+                }
+            }
+            Some(::FormOf {
+                lemma: lemma.clone(),
+                tags,
+            })
+        })
+        .collect()
+}
+
+fn parse_tag(word: &str) -> Option<::GrammaticalTag> {
+    // Adjectives such as `jednotného` are recognized by their stem regardless of their case.
+    for (stem, tag) in &[
+        ("akuzativ", ::GrammaticalTag::Accusative),
+        ("budouc", ::GrammaticalTag::Future),
+        ("dativ", ::GrammaticalTag::Dative),
+        ("duál", ::GrammaticalTag::Dual),
+        ("dvojn", ::GrammaticalTag::Dual),
+        ("genitiv", ::GrammaticalTag::Genitive),
+        ("imperativ", ::GrammaticalTag::Imperative),
+        ("indikativ", ::GrammaticalTag::Indicative),
+        ("instrumentál", ::GrammaticalTag::Instrumental),
+        ("jednotn", ::GrammaticalTag::Singular),
+        ("kondicionál", ::GrammaticalTag::Conditional),
+        ("lokál", ::GrammaticalTag::Locative),
+        ("lokativ", ::GrammaticalTag::Locative),
+        ("minul", ::GrammaticalTag::Past),
+        ("množn", ::GrammaticalTag::Plural),
+        ("nominativ", ::GrammaticalTag::Nominative),
+        ("oznamovac", ::GrammaticalTag::Indicative),
+        ("pasiv", ::GrammaticalTag::Passive),
+        ("plurál", ::GrammaticalTag::Plural),
+        ("podmiňovac", ::GrammaticalTag::Conditional),
+        ("přechodník", ::GrammaticalTag::Transgressive),
+        ("příčestí", ::GrammaticalTag::Participle),
+        ("přítomn", ::GrammaticalTag::Present),
+        ("rozkazovac", ::GrammaticalTag::Imperative),
+        ("singulár", ::GrammaticalTag::Singular),
+        ("trpn", ::GrammaticalTag::Passive),
+        ("vokativ", ::GrammaticalTag::Vocative),
+    ] {
+        if word.starts_with(stem) {
+            return Some(*tag);
+        }
+    }
+    None
+}
@@ -15,11 +15,21 @@ pub fn parse_hyphenation<'a>(
         output,
         |context, list_node, list_items| {
             if let [item] = list_items {
-                if let text @ Some(_) = ::parse_text(&item.nodes) {
-                    return text;
+                if let Some(text) = ::parse_text(&item.nodes) {
+                    ::check_title(
+                        context,
+                        item,
+                        ::WarningCode::HyphenationTitleMismatch,
+                        |title| {
+                            text.chars()
+                                .filter(|character| character.is_alphanumeric())
+                                .eq(title.chars().filter(|character| character.is_alphanumeric()))
+                        },
+                    );
+                    return Some(text);
                 }
             }
-            ::add_warning(context, list_node, ::WarningMessage::ValueUnrecognized);
+            ::add_warning(context, list_node, ::WarningCode::InvalidHyphenation);
             None
         },
     )
@@ -166,12 +166,12 @@ pub fn parse_inflection_basic<'a, T1: Default + Inflection<'a>, T2>(
     let mut inflection = T1::default();
     for parameter in parameters {
         if let Some(name) = ::parse_parameter_name(parameter) {
-            if let Some(terms) = inflection.get_field(&name) {
+            if let Some(terms) = inflection.get_field(name) {
                 ::inflection_field::parse_inflection_field(context, parameter, terms);
                 continue;
             }
         }
-        ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+        ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
     }
     Some(variant(inflection))
 }
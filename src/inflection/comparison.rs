@@ -12,7 +12,7 @@ pub fn parse_comparison_template<'a>(
     let mut superlative = (None, None, None);
     for parameter in parameters {
         if let Some(name) = ::parse_parameter_name(parameter) {
-            let output = match &name as _ {
+            let output = match name {
                 "komp" => &mut comparative.0,
                 "komp2" => &mut comparative.1,
                 "komp3" => &mut comparative.2,
@@ -22,23 +22,23 @@ pub fn parse_comparison_template<'a>(
                 "sup2" => &mut superlative.1,
                 "sup3" => &mut superlative.2,
                 _ => {
-                    ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                    ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
                     return None;
                 }
             };
             if output.is_some() {
-                ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+                ::add_warning(context, parameter, ::WarningCode::DuplicateParameter);
                 return None;
             }
             *output = ::parse_text_not_empty(&parameter.value);
             if output.is_none() {
-                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                ::add_warning(context, parameter, ::WarningCode::InvalidParameterValue);
                 return None;
             }
         }
     }
     if positive.0.is_none() {
-        ::add_warning(context, template_node, ::WarningMessage::Empty);
+        ::add_warning(context, template_node, ::WarningCode::MissingParameter);
         return None;
     }
     Some(::Inflection::Comparison(super::Comparison {
@@ -48,13 +48,13 @@ pub fn parse_comparison_template<'a>(
             (Some(item1), Some(item2), None) => vec![item1, item2],
             (Some(item1), Some(item2), Some(item3)) => vec![item1, item2, item3],
             _ => {
-                ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+                ::add_warning(context, template_node, ::WarningCode::InvalidTemplateParameters);
                 return None;
             }
         },
         positive: match positive {
             (None, _) => {
-                ::add_warning(context, template_node, ::WarningMessage::Empty);
+                ::add_warning(context, template_node, ::WarningCode::MissingParameter);
                 return None;
             }
             (Some(item1), None) => vec![item1],
@@ -66,7 +66,7 @@ pub fn parse_comparison_template<'a>(
             (Some(item1), Some(item2), None) => vec![item1, item2],
             (Some(item1), Some(item2), Some(item3)) => vec![item1, item2, item3],
             _ => {
-                ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+                ::add_warning(context, template_node, ::WarningCode::InvalidTemplateParameters);
                 return None;
             }
         },
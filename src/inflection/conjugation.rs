@@ -3,7 +3,7 @@
 // the file LICENSE at the top-level directory of this distribution.
 
 macro_rules! parse_conjugation_template {
-    { fields { $( $field_name:ident )+ } groups { $( $flag_name:ident $flag_value:tt $( $group_field_name:ident )* ),+ } } => {
+    { perfective $perfective_flag_name:ident fields { $( $field_name:ident )+ } groups { $( $flag_name:ident $flag_value:tt $( $group_field_name:ident )* ),+ } } => {
         pub fn parse_conjugation_template<'a>(
             context: &mut ::Context<'a>,
             _template_node: &::Node,
@@ -36,15 +36,20 @@ macro_rules! parse_conjugation_template {
                         _ => {}
                     }
                 }
-                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
                 return None;
             }
+            // Without `dok=ano` the verb may be imperfective or biaspectual, which the template doesn't tell apart.
+            if $perfective_flag_name == Some(true) {
+                conjugation.aspect = Some(::Aspect::Perfective);
+            }
             Some(::Inflection::Conjugation(conjugation))
         }
     }
 }
 
 parse_conjugation_template! {
+    perfective dok
     fields { pactf pactm pimp1 pimp2 ppre1 ppre2 ppre3 sactf sactm sactn simp2 spre1 spre2 spre3 }
     groups {
         dok "ano",
@@ -12,15 +12,42 @@ mod numeral;
 
 macro_rules! inflection {
     { $documentation:tt, $name:ident $( $field:ident )+ } => {
+        inflection! { $documentation, $name $( $field )+ ; }
+    };
+    { $documentation:tt, $name:ident $( $field:ident )+ ; $( $other_documentation:tt $other_field:ident $other_type:ty ),* } => {
         #[derive(Debug, Default, Deserialize, Serialize)]
         #[doc=$documentation]
         pub struct $name<'a> {
+            $(
+                #[doc=$other_documentation]
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub $other_field: Option<$other_type>,
+            )*
             $(
                 #[allow(missing_docs)]
                 #[serde(default, skip_serializing_if = "Vec::is_empty")]
                 pub $field: Vec<::Cow<'a, str>>
             ),*
         }
+
+        impl<'a> ::owned::IntoStatic for $name<'a> {
+            type Static = $name<'static>;
+
+            fn into_static(self) -> Self::Static {
+                $name {
+                    $( $other_field: ::owned::IntoStatic::into_static(self.$other_field), )*
+                    $( $field: ::owned::IntoStatic::into_static(self.$field) ),*
+                }
+            }
+        }
+
+        impl<'a> $name<'a> {
+            /// Converts into a value that doesn't borrow from the wiki text, by copying all borrowed strings.
+            #[must_use]
+            pub fn into_owned(self) -> $name<'static> {
+                ::owned::IntoStatic::into_static(self)
+            }
+        }
     }
 }
 
@@ -61,7 +88,8 @@ inflection! {
 
 inflection! {
     "Conjugation of verbs.",
-    Conjugation mtraf mtram mtrap pactf pactm pimp1 pimp2 ppasf ppasm ppre1 ppre2 ppre3 ptraf ptram ptrap sactf sactm sactn simp2 spasf spasm spasn spre1 spre2 spre3
+    Conjugation mtraf mtram mtrap pactf pactm pimp1 pimp2 ppasf ppasm ppre1 ppre2 ppre3 ptraf ptram ptrap sactf sactm sactn simp2 spasf spasm spasn spre1 spre2 spre3;
+    "The aspect of the verb: perfective if the template has the parameter `dok=ano`, otherwise not known from the template." aspect ::Aspect
 }
 
 macro_rules! parse_inflection { {
@@ -84,23 +112,25 @@ macro_rules! parse_inflection { {
                 ::Node::Heading { .. } => break,
                 ::Node::Template { name, parameters, .. } => if let Some(name) = ::parse_text(name) {
                     match &name as _ {
-                        $( $basic_template_name if pos == ::Pos::$basic_pos && context.language.unwrap() == ::Language::Cs => {
+                        $( $basic_template_name if pos == ::Pos::$basic_pos && context.language == Some(::EntryLanguage::Known(::Language::Cs)) => {
                             node_index += 1;
                             inflection = Some(if inflection.is_some() {
-                                ::add_warning(context, node, ::WarningMessage::Duplicate);
+                                ::add_warning(context, node, ::WarningCode::DuplicateTemplate);
                                 None
                             } else {
-                                ::inflection::basic::parse_inflection_basic(context, parameters, ::Inflection::$variant)
+                                let inflection = ::inflection::basic::parse_inflection_basic(context, parameters, ::Inflection::$variant);
+                                check_title(context, node, inflection)
                             });
                             continue;
                         } )*
-                        $( $special_template_name if pos == ::Pos::$special_pos && context.language.unwrap() == ::Language::Cs => {
+                        $( $special_template_name if pos == ::Pos::$special_pos && context.language == Some(::EntryLanguage::Known(::Language::Cs)) => {
                             node_index += 1;
                             inflection = Some(if inflection.is_some() {
-                                ::add_warning(context, node, ::WarningMessage::Duplicate);
+                                ::add_warning(context, node, ::WarningCode::DuplicateTemplate);
                                 None
                             } else {
-                                $function_name(context, node, parameters)
+                                let inflection = $function_name(context, node, parameters);
+                                check_title(context, node, inflection)
                             });
                             continue;
                         } )*
@@ -118,7 +148,7 @@ macro_rules! parse_inflection { {
             ::unrecognized_unless_ignored(context, node);
         }
         if details.is_none() && inflection.is_none() {
-            ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+            ::add_warning(context, heading_node, ::WarningCode::EmptySection);
         } else {
             output.push(::InflectionEntry {
                 details: details.unwrap_or_default(),
@@ -129,6 +159,40 @@ macro_rules! parse_inflection { {
     }
 } }
 
+fn check_title<'a>(
+    context: &mut ::Context,
+    template_node: &::Node,
+    inflection: Option<::Inflection<'a>>,
+) -> Option<::Inflection<'a>> {
+    if let Some(inflection) = &inflection {
+        let forms: &[&[::Cow<str>]] = match inflection {
+            ::Inflection::AdjectiveDeclensionBasic(inflection)
+            | ::Inflection::NumeralDeclensionAdjective(inflection) => {
+                &[&inflection.snomm, &inflection.snomma]
+            }
+            ::Inflection::Comparison(inflection) => &[&inflection.positive],
+            ::Inflection::Conjugation(_) | ::Inflection::Indeclinable => &[],
+            ::Inflection::NounDeclensionBasic(inflection) => &[&inflection.snom, &inflection.pnom],
+            ::Inflection::NumeralDeclensionBasic(inflection) => &[&inflection.nom],
+            ::Inflection::NumeralDeclensionSgPl(inflection) => &[&inflection.snom, &inflection.pnom],
+            ::Inflection::PronounDeclensionAdjective(inflection) => {
+                &[&inflection.snomm, &inflection.snomma]
+            }
+            ::Inflection::PronounDeclensionBasic(inflection) => &[&inflection.nom],
+        };
+        // An inflection that doesn't specify any of the basic forms is not checked.
+        if forms.iter().any(|forms| !forms.is_empty()) {
+            ::check_title(
+                context,
+                template_node,
+                ::WarningCode::InflectionTitleMismatch,
+                |title| forms.iter().any(|forms| forms.iter().any(|form| form == title)),
+            );
+        }
+    }
+    inflection
+}
+
 parse_inflection! {
     name: parse_comparison,
     special_template: Adjective "Stupňování (cs)" ::inflection::comparison::parse_comparison_template,
@@ -147,5 +211,6 @@ parse_inflection! {
     basic_template: Pronoun "Zájmeno (cs)" PronounDeclensionBasic,
     basic_template: Pronoun "Zájmeno adj (cs)" PronounDeclensionAdjective,
     special_template: Noun "Substantivum (cs)" ::inflection::noun::parse_noun_declension_basic,
-    special_template: Numeral "Číslovka (cs)" ::inflection::numeral::parse_numeral_declension_basic
+    special_template: Numeral "Číslovka (cs)" ::inflection::numeral::parse_numeral_declension_basic,
+    special_template: ProperNoun "Substantivum (cs)" ::inflection::noun::parse_noun_declension_basic
 }
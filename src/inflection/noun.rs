@@ -11,7 +11,7 @@ pub fn parse_noun_declension_basic<'a>(
     let mut indeclinable = None;
     for parameter in parameters {
         if let Some(name) = ::parse_parameter_name(parameter) {
-            let terms = match &name as _ {
+            let terms = match name {
                 "dacc" => &mut inflection.dacc,
                 "ddat" => &mut inflection.ddat,
                 "dgen" => &mut inflection.dgen,
@@ -34,7 +34,7 @@ pub fn parse_noun_declension_basic<'a>(
                 "snom" => &mut inflection.snom,
                 "svoc" => &mut inflection.svoc,
                 _ => {
-                    ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                    ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
                     return None;
                 }
             };
@@ -50,7 +50,7 @@ pub fn parse_noun_declension_basic<'a>(
             indeclinable = Some(true);
             continue;
         }
-        ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+        ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
         return None;
     }
     Some(if indeclinable == Some(true) {
@@ -25,11 +25,11 @@ macro_rules! parse_numeral_declension_basic {
                         _ => {}
                     }
                 }
-                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
                 return None;
             }
             if inflection.is_none() {
-                ::add_warning(context, template_node, ::WarningMessage::Empty);
+                ::add_warning(context, template_node, ::WarningCode::EmptyTemplate);
             }
             inflection
         }
@@ -4,7 +4,7 @@
 
 macro_rules! unrecognized {
     ($context:expr, $node:expr) => {{
-        ::add_warning($context, $node, ::WarningMessage::Unrecognized);
+        ::add_warning($context, $node, ::WarningCode::UnrecognizedInflectionForms);
         return;
     }};
 }
@@ -15,7 +15,7 @@ pub fn parse_inflection_field<'a>(
     output: &mut Vec<::Cow<'a, str>>,
 ) {
     if !output.is_empty() {
-        ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+        ::add_warning(context, parameter, ::WarningCode::DuplicateParameter);
     }
     let mut expecting_separator = false;
     let mut terms = vec![];
@@ -60,7 +60,7 @@ pub fn parse_inflection_field<'a>(
         }
     }
     if terms.is_empty() {
-        ::add_warning(context, parameter, ::WarningMessage::Empty);
+        ::add_warning(context, parameter, ::WarningCode::EmptyParameter);
     }
     *output = terms;
 }
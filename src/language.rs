@@ -2,16 +2,18 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
+use parse_wiki_text::Positioned;
+
 pub fn parse_language<'a>(
     context: &mut ::Context<'a>,
     heading_node: &::Node,
     nodes: &[::Node<'a>],
     language_entries: &mut Vec<::LanguageEntry<'a>>,
-    language: ::Language,
+    language: ::EntryLanguage<'a>,
 ) -> usize {
     for entry in language_entries.iter() {
         if entry.language == language {
-            ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
+            ::add_warning(context, heading_node, ::WarningCode::DuplicateLanguageSection);
             break;
         }
     }
@@ -21,24 +23,13 @@ pub fn parse_language<'a>(
     let mut pos_entries = vec![];
     let mut pronunciation = None;
     let mut variants = None;
-    context.language = Some(language);
+    context.language = Some(language.clone());
     while let Some(node) = nodes.get(node_index) {
         macro_rules! parse_section {
             ($output:tt $function:path) => {
                 $function(context, node, &nodes[node_index..], &mut $output)
             };
         }
-        macro_rules! parse_pos {
-            ($pos:tt) => {
-                ::pos::parse_pos(
-                    context,
-                    node,
-                    &nodes[node_index..],
-                    &mut pos_entries,
-                    ::Pos::$pos,
-                )
-            };
-        }
         match node {
             ::Node::Heading {
                 level,
@@ -52,85 +43,62 @@ pub fn parse_language<'a>(
                 node_index += 1;
                 node_index += match title.as_slice() {
                     [::Node::Text { value, .. }] => match *value {
-                        "citoslovce" | "citoslovce (1)" | "citoslovce (2)" | "citoslovce (3)"
-                        | "citoslovce (4)" | "citoslovce (5)" => parse_pos!(Interjection),
                         "dělení" => parse_section!(hyphenation::hyphenation::parse_hyphenation),
                         "etymologie" => parse_section!(etymology::etymology::parse_etymology),
-                        "idiom" | "idiom (1)" | "idiom (2)" | "idiom (3)" | "idiom (4)"
-                        | "idiom (5)" => parse_pos!(Idiom),
-                        "podstatné jméno"
-                        | "podstatné jméno (1)"
-                        | "podstatné jméno (2)"
-                        | "podstatné jméno (3)"
-                        | "podstatné jméno (4)"
-                        | "podstatné jméno (5)" => parse_pos!(Noun),
-                        "předložka" | "předložka (1)" | "předložka (2)"
-                        | "předložka (3)" | "předložka (4)" | "předložka (5)" => {
-                            parse_pos!(Preposition)
-                        }
-                        "předpona" | "předpona (1)" | "předpona (2)" | "předpona (3)"
-                        | "předpona (4)" | "předpona (5)" => parse_pos!(Prefix),
-                        "přídavné jméno"
-                        | "přídavné jméno (1)"
-                        | "přídavné jméno (2)"
-                        | "přídavné jméno (3)"
-                        | "přídavné jméno (4)"
-                        | "přídavné jméno (5)" => parse_pos!(Adjective),
-                        "přípona" | "přípona (1)" | "přípona (2)" | "přípona (3)"
-                        | "přípona (4)" | "přípona (5)" => parse_pos!(Suffix),
-                        "příslovce" | "příslovce (1)" | "příslovce (2)"
-                        | "příslovce (3)" | "příslovce (4)" | "příslovce (5)" => {
-                            parse_pos!(Adverb)
-                        }
-                        "přísloví" | "přísloví (1)" | "přísloví (2)"
-                        | "přísloví (3)" | "přísloví (4)" | "přísloví (5)" => {
-                            parse_pos!(Proverb)
-                        }
-                        "rčení" | "rčení (1)" | "rčení (2)" | "rčení (3)"
-                        | "rčení (4)" | "rčení (5)" => parse_pos!(Proverb),
-                        "sloveso" | "sloveso (1)" | "sloveso (2)" | "sloveso (3)"
-                        | "sloveso (4)" | "sloveso (5)" => parse_pos!(Verb),
-                        "slovní spojení"
-                        | "slovní spojení (1)"
-                        | "slovní spojení (2)"
-                        | "slovní spojení (3)"
-                        | "slovní spojení (4)"
-                        | "slovní spojení (5)" => parse_pos!(CompoundWord),
-                        "spojka" | "spojka (1)" | "spojka (2)" | "spojka (3)" | "spojka (4)"
-                        | "spojka (5)" => parse_pos!(Conjunction),
                         "varianty" => parse_section!(variants::related_terms::parse_related_terms),
                         "výslovnost" => {
                             parse_section!(pronunciation::pronunciation::parse_pronunciation)
                         }
-                        "zkratka" | "zkratka (1)" | "zkratka (2)" | "zkratka (3)"
-                        | "zkratka (4)" | "zkratka (5)" => parse_pos!(Abbreviation),
-                        "zájmeno" | "zájmeno (1)" | "zájmeno (2)" | "zájmeno (3)"
-                        | "zájmeno (4)" | "zájmeno (5)" => parse_pos!(Pronoun),
-                        "částice" | "částice (1)" | "částice (2)" | "částice (3)"
-                        | "částice (4)" | "částice (5)" => parse_pos!(Particle),
-                        "číslovka" | "číslovka (1)" | "číslovka (2)" | "číslovka (3)"
-                        | "číslovka (4)" | "číslovka (5)" => parse_pos!(Numeral),
                         _ => {
-                            ::add_warning(context, node, ::WarningMessage::Unrecognized);
-                            0
+                            let (heading, homonym_index) = split_homonym_index(value);
+                            let (pos, form) = match parse_form_heading(heading) {
+                                None => (parse_pos_heading(heading), false),
+                                Some(pos) => (Some(pos), true),
+                            };
+                            match pos {
+                                None => {
+                                    ::add_warning(
+                                        context,
+                                        node,
+                                        ::WarningCode::UnrecognizedHeading,
+                                    );
+                                    0
+                                }
+                                Some(pos) => ::pos::parse_pos(
+                                    context,
+                                    node,
+                                    &nodes[node_index..],
+                                    &mut pos_entries,
+                                    pos,
+                                    homonym_index,
+                                    form,
+                                ),
+                            }
                         }
                     },
                     _ => {
-                        ::add_warning(context, node, ::WarningMessage::Unrecognized);
+                        ::add_warning(context, node, ::WarningCode::UnrecognizedHeading);
                         0
                     }
                 };
             }
             _ => {
                 node_index += 1;
-                ::add_warning(context, node, ::WarningMessage::Unrecognized);
+                ::add_warning(context, node, ::WarningCode::UnrecognizedElement);
             }
         }
     }
     if pos_entries.is_empty() {
-        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+        ::add_warning(context, heading_node, ::WarningCode::MissingPosSection);
     }
     context.language = None;
+    context.language_sections.push((
+        heading_node.start(),
+        nodes[..node_index]
+            .last()
+            .map_or(heading_node.end(), Positioned::end),
+        language.clone(),
+    ));
     let pronunciation = pronunciation.unwrap_or_default().unwrap_or_default();
     language_entries.push(::LanguageEntry {
         audio: pronunciation.audio.into_iter().collect(),
@@ -144,3 +112,60 @@ pub fn parse_language<'a>(
     });
     node_index
 }
+
+fn parse_form_heading(heading: &str) -> Option<::Pos> {
+    Some(match heading {
+        "tvar podstatného jména" => ::Pos::Noun,
+        "tvar přídavného jména" => ::Pos::Adjective,
+        "tvar příslovce" => ::Pos::Adverb,
+        "tvar slovesa" => ::Pos::Verb,
+        "tvar vlastního jména" => ::Pos::ProperNoun,
+        "tvar zájmena" => ::Pos::Pronoun,
+        "tvar číslovky" => ::Pos::Numeral,
+        _ => return None,
+    })
+}
+
+fn parse_pos_heading(heading: &str) -> Option<::Pos> {
+    Some(match heading {
+        "citoslovce" => ::Pos::Interjection,
+        "fráze" => ::Pos::Phrase,
+        "idiom" => ::Pos::Idiom,
+        "podstatné jméno" => ::Pos::Noun,
+        "předložka" => ::Pos::Preposition,
+        "předpona" => ::Pos::Prefix,
+        "přídavné jméno" => ::Pos::Adjective,
+        "přípona" => ::Pos::Suffix,
+        "příslovce" => ::Pos::Adverb,
+        "přísloví" | "rčení" => ::Pos::Proverb,
+        "sloveso" => ::Pos::Verb,
+        "slovní spojení" => ::Pos::CompoundWord,
+        "spojka" => ::Pos::Conjunction,
+        "ustálené spojení" => ::Pos::FixedExpression,
+        "vlastní jméno" => ::Pos::ProperNoun,
+        "zkratka" => ::Pos::Abbreviation,
+        "znak" => ::Pos::Character,
+        "značka" => ::Pos::Symbol,
+        "zájmeno" => ::Pos::Pronoun,
+        "částice" => ::Pos::Particle,
+        "číslovka" => ::Pos::Numeral,
+        _ => return None,
+    })
+}
+
+// Splits a heading such as `sloveso (2)` into the heading without the number and the number.
+fn split_homonym_index(heading: &str) -> (&str, Option<usize>) {
+    let heading = heading.trim();
+    if heading.ends_with(')') {
+        if let Some(position) = heading.rfind('(') {
+            let number = heading[position + 1..heading.len() - 1].trim();
+            // Signs are not accepted, and homonyms are numbered from 1.
+            if number.bytes().all(|byte| byte.is_ascii_digit()) {
+                if let Ok(number @ 1..) = number.parse() {
+                    return (heading[..position].trim_end(), Some(number));
+                }
+            }
+        }
+    }
+    (heading, None)
+}
@@ -0,0 +1,465 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+/// Family of languages, or a group of languages that don't belong to a family by descent, such as constructed languages.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LanguageFamily {
+    /// Afroasiatic languages, such as Arabic, Hebrew and Hausa.
+    Afroasiatic,
+
+    /// Algic languages, such as Ojibwe.
+    Algic,
+
+    /// Araucanian languages, such as Mapuche.
+    Araucanian,
+
+    /// Arawakan languages, such as Garifuna.
+    Arawakan,
+
+    /// Austroasiatic languages, such as Vietnamese and Khmer.
+    Austroasiatic,
+
+    /// Austronesian languages, such as Indonesian, Tagalog and Maori.
+    Austronesian,
+
+    /// Aymaran languages, such as Aymara.
+    Aymaran,
+
+    /// Chukotko-Kamchatkan languages, such as Chukchi.
+    ChukotkoKamchatkan,
+
+    /// Constructed languages, such as Esperanto.
+    Constructed,
+
+    /// Creole languages, such as Haitian Creole.
+    Creole,
+
+    /// Dravidian languages, such as Tamil.
+    Dravidian,
+
+    /// Eskimo-Aleut languages, such as Greenlandic.
+    EskimoAleut,
+
+    /// Hmong-Mien languages, such as White Hmong.
+    HmongMien,
+
+    /// Indo-European languages, such as Czech, English and Hindi.
+    IndoEuropean,
+
+    /// Iroquoian languages, such as Cherokee.
+    Iroquoian,
+
+    /// Language isolates, such as Basque.
+    Isolate,
+
+    /// Japonic languages, such as Japanese.
+    Japonic,
+
+    /// Kartvelian languages, such as Georgian.
+    Kartvelian,
+
+    /// Koreanic languages, such as Korean.
+    Koreanic,
+
+    /// Kra-Dai languages, such as Thai.
+    KraDai,
+
+    /// Mongolic languages, such as Mongolian.
+    Mongolic,
+
+    /// Na-Dene languages, such as Navajo.
+    NaDene,
+
+    /// Niger-Congo languages, such as Swahili and Yoruba.
+    NigerCongo,
+
+    /// Nilo-Saharan languages, such as Maasai.
+    NiloSaharan,
+
+    /// Northeast Caucasian languages, such as Chechen.
+    NortheastCaucasian,
+
+    /// Northwest Caucasian languages, such as Abkhaz.
+    NorthwestCaucasian,
+
+    /// Quechuan languages, such as Quechua.
+    Quechuan,
+
+    /// Sepik languages, such as Abau.
+    Sepik,
+
+    /// Sino-Tibetan languages, such as Chinese and Tibetan.
+    SinoTibetan,
+
+    /// Tungusic languages, such as Manchu.
+    Tungusic,
+
+    /// Tupian languages, such as Guarani.
+    Tupian,
+
+    /// Turkic languages, such as Turkish.
+    Turkic,
+
+    /// Uralic languages, such as Finnish and Hungarian.
+    Uralic,
+
+    /// Uto-Aztecan languages, such as Nahuatl.
+    UtoAztecan,
+}
+
+impl ::Language {
+    /// Returns the [BCP 47](https://tools.ietf.org/html/bcp47) language tag for the language.
+    ///
+    /// This differs from the language code when the language code is not a valid language tag or identifies a different language than the one used on Wiktionary.
+    pub fn bcp47_tag(self) -> &'static str {
+        metadata(self).2
+    }
+
+    /// Returns the family the language belongs to.
+    pub fn family(self) -> LanguageFamily {
+        metadata(self).4
+    }
+
+    /// Returns the two-letter [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) code for the language, if any.
+    pub fn iso_639_1(self) -> Option<&'static str> {
+        metadata(self).0
+    }
+
+    /// Returns the three-letter [ISO 639-3](https://en.wikipedia.org/wiki/ISO_639-3) code for the language, if any.
+    ///
+    /// There is no code for languages that are groups of languages, such as `nah` (Nahuatl languages).
+    pub fn iso_639_3(self) -> Option<&'static str> {
+        metadata(self).1
+    }
+
+    /// Returns the [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) code for the script the language is primarily written in, for example `Latn` or `Cyrl`.
+    pub fn script(self) -> &'static str {
+        metadata(self).3
+    }
+}
+
+// The fields are the ISO 639-1 code, ISO 639-3 code, BCP 47 tag, ISO 15924 script code and family.
+fn metadata(
+    language: ::Language,
+) -> (
+    Option<&'static str>,
+    Option<&'static str>,
+    &'static str,
+    &'static str,
+    LanguageFamily,
+) {
+    match language {
+        ::Language::Aa => (Some("aa"), Some("aar"), "aa", "Latn", LanguageFamily::Afroasiatic),
+        ::Language::Aau => (None, Some("aau"), "aau", "Latn", LanguageFamily::Sepik),
+        ::Language::Ab => (Some("ab"), Some("abk"), "ab", "Cyrl", LanguageFamily::NorthwestCaucasian),
+        ::Language::Abe => (None, Some("abe"), "abe", "Latn", LanguageFamily::Algic),
+        ::Language::Ady => (None, Some("ady"), "ady", "Cyrl", LanguageFamily::NorthwestCaucasian),
+        ::Language::Af => (Some("af"), Some("afr"), "af", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ain => (None, Some("ain"), "ain", "Kana", LanguageFamily::Isolate),
+        ::Language::Ak => (Some("ak"), Some("aka"), "ak", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Ale => (None, Some("ale"), "ale", "Latn", LanguageFamily::EskimoAleut),
+        ::Language::Als => (None, Some("gsw"), "gsw", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Alt => (None, Some("alt"), "alt", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Am => (Some("am"), Some("amh"), "am", "Ethi", LanguageFamily::Afroasiatic),
+        ::Language::An => (Some("an"), Some("arg"), "an", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ang => (None, Some("ang"), "ang", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ar => (Some("ar"), Some("ara"), "ar", "Arab", LanguageFamily::Afroasiatic),
+        ::Language::Arc => (None, Some("arc"), "arc", "Hebr", LanguageFamily::Afroasiatic),
+        ::Language::Arn => (None, Some("arn"), "arn", "Latn", LanguageFamily::Araucanian),
+        ::Language::Arz => (None, Some("arz"), "arz", "Arab", LanguageFamily::Afroasiatic),
+        ::Language::As => (Some("as"), Some("asm"), "as", "Beng", LanguageFamily::IndoEuropean),
+        ::Language::Ast => (None, Some("ast"), "ast", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Atv => (None, Some("atv"), "atv", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Ay => (Some("ay"), Some("aym"), "ay", "Latn", LanguageFamily::Aymaran),
+        ::Language::Az => (Some("az"), Some("aze"), "az", "Latn", LanguageFamily::Turkic),
+        ::Language::Azb => (None, Some("azb"), "azb", "Arab", LanguageFamily::Turkic),
+        ::Language::Ba => (Some("ba"), Some("bak"), "ba", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Bal => (None, Some("bal"), "bal", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Bar => (None, Some("bar"), "bar", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Bcl => (None, Some("bcl"), "bcl", "Latn", LanguageFamily::Austronesian),
+        ::Language::Be => (Some("be"), Some("bel"), "be", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Bem => (None, Some("bem"), "bem", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Bg => (Some("bg"), Some("bul"), "bg", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Bi => (Some("bi"), Some("bis"), "bi", "Latn", LanguageFamily::Creole),
+        ::Language::Bjn => (None, Some("bjn"), "bjn", "Latn", LanguageFamily::Austronesian),
+        ::Language::Bm => (Some("bm"), Some("bam"), "bm", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Bn => (Some("bn"), Some("ben"), "bn", "Beng", LanguageFamily::IndoEuropean),
+        ::Language::Bo => (Some("bo"), Some("bod"), "bo", "Tibt", LanguageFamily::SinoTibetan),
+        ::Language::Bpy => (None, Some("bpy"), "bpy", "Beng", LanguageFamily::IndoEuropean),
+        ::Language::Br => (Some("br"), Some("bre"), "br", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Bs => (Some("bs"), Some("bos"), "bs", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Bxr => (None, Some("bxr"), "bxr", "Cyrl", LanguageFamily::Mongolic),
+        ::Language::Ca => (Some("ca"), Some("cat"), "ca", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Cab => (None, Some("cab"), "cab", "Latn", LanguageFamily::Arawakan),
+        ::Language::Ce => (Some("ce"), Some("che"), "ce", "Cyrl", LanguageFamily::NortheastCaucasian),
+        ::Language::Ceb => (None, Some("ceb"), "ceb", "Latn", LanguageFamily::Austronesian),
+        ::Language::Ch => (Some("ch"), Some("cha"), "ch", "Latn", LanguageFamily::Austronesian),
+        ::Language::Chk => (None, Some("ckt"), "ckt", "Cyrl", LanguageFamily::ChukotkoKamchatkan),
+        ::Language::Chp => (None, Some("chp"), "chp", "Latn", LanguageFamily::NaDene),
+        ::Language::Chr => (None, Some("chr"), "chr", "Cher", LanguageFamily::Iroquoian),
+        ::Language::Cim => (None, Some("cim"), "cim", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ckb => (None, Some("ckb"), "ckb", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Co => (Some("co"), Some("cos"), "co", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Cpf => (None, None, "cpf", "Latn", LanguageFamily::Creole),
+        ::Language::Cps => (None, Some("cps"), "cps", "Latn", LanguageFamily::Austronesian),
+        ::Language::Crh => (None, Some("crh"), "crh", "Latn", LanguageFamily::Turkic),
+        ::Language::Cs => (Some("cs"), Some("ces"), "cs", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Csb => (None, Some("csb"), "csb", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Cu => (Some("cu"), Some("chu"), "cu", "Cyrs", LanguageFamily::IndoEuropean),
+        ::Language::Cv => (Some("cv"), Some("chv"), "cv", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Cy => (Some("cy"), Some("cym"), "cy", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Da => (Some("da"), Some("dan"), "da", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::De => (Some("de"), Some("deu"), "de", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Dgd => (None, Some("dgd"), "dgd", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Dje => (None, Some("dje"), "dje", "Latn", LanguageFamily::NiloSaharan),
+        ::Language::Dlm => (None, Some("dlm"), "dlm", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Dsb => (None, Some("dsb"), "dsb", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Dv => (Some("dv"), Some("div"), "dv", "Thaa", LanguageFamily::IndoEuropean),
+        ::Language::Dz => (Some("dz"), Some("dzo"), "dz", "Tibt", LanguageFamily::SinoTibetan),
+        ::Language::Ee => (Some("ee"), Some("ewe"), "ee", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Egy => (None, Some("egy"), "egy", "Egyp", LanguageFamily::Afroasiatic),
+        ::Language::El => (Some("el"), Some("ell"), "el", "Grek", LanguageFamily::IndoEuropean),
+        ::Language::En => (Some("en"), Some("eng"), "en", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Eo => (Some("eo"), Some("epo"), "eo", "Latn", LanguageFamily::Constructed),
+        ::Language::Es => (Some("es"), Some("spa"), "es", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Et => (Some("et"), Some("est"), "et", "Latn", LanguageFamily::Uralic),
+        ::Language::Eu => (Some("eu"), Some("eus"), "eu", "Latn", LanguageFamily::Isolate),
+        ::Language::Ext => (None, Some("ext"), "ext", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Fa => (Some("fa"), Some("fas"), "fa", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Fi => (Some("fi"), Some("fin"), "fi", "Latn", LanguageFamily::Uralic),
+        ::Language::Fil => (None, Some("fil"), "fil", "Latn", LanguageFamily::Austronesian),
+        ::Language::Fj => (Some("fj"), Some("fij"), "fj", "Latn", LanguageFamily::Austronesian),
+        ::Language::Fkv => (None, Some("fkv"), "fkv", "Latn", LanguageFamily::Uralic),
+        ::Language::Fo => (Some("fo"), Some("fao"), "fo", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Fr => (Some("fr"), Some("fra"), "fr", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Frp => (None, Some("frp"), "frp", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Frr => (None, Some("frr"), "frr", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Fur => (None, Some("fur"), "fur", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Fy => (Some("fy"), Some("fry"), "fy", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ga => (Some("ga"), Some("gle"), "ga", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Gad => (None, Some("gad"), "gad", "Latn", LanguageFamily::Austronesian),
+        ::Language::Gag => (None, Some("gag"), "gag", "Latn", LanguageFamily::Turkic),
+        ::Language::Gd => (Some("gd"), Some("gla"), "gd", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Gil => (None, Some("gil"), "gil", "Latn", LanguageFamily::Austronesian),
+        ::Language::Gl => (Some("gl"), Some("glg"), "gl", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Gmh => (None, Some("gmh"), "gmh", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Gml => (None, Some("gml"), "gml", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Gn => (Some("gn"), Some("grn"), "gn", "Latn", LanguageFamily::Tupian),
+        ::Language::Got => (None, Some("got"), "got", "Goth", LanguageFamily::IndoEuropean),
+        ::Language::Grc => (None, Some("grc"), "grc", "Grek", LanguageFamily::IndoEuropean),
+        ::Language::Gsw => (None, Some("gsw"), "gsw", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Gu => (Some("gu"), Some("guj"), "gu", "Gujr", LanguageFamily::IndoEuropean),
+        ::Language::Guz => (None, Some("guz"), "guz", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Gv => (Some("gv"), Some("glv"), "gv", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ha => (Some("ha"), Some("hau"), "ha", "Latn", LanguageFamily::Afroasiatic),
+        ::Language::Hak => (None, Some("hak"), "hak", "Hani", LanguageFamily::SinoTibetan),
+        ::Language::Haw => (None, Some("haw"), "haw", "Latn", LanguageFamily::Austronesian),
+        ::Language::He => (Some("he"), Some("heb"), "he", "Hebr", LanguageFamily::Afroasiatic),
+        ::Language::Hi => (Some("hi"), Some("hin"), "hi", "Deva", LanguageFamily::IndoEuropean),
+        ::Language::Hil => (None, Some("hil"), "hil", "Latn", LanguageFamily::Austronesian),
+        ::Language::Hit => (None, Some("hit"), "hit", "Xsux", LanguageFamily::IndoEuropean),
+        ::Language::Hnd => (None, Some("hnd"), "hnd", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Hr => (Some("hr"), Some("hrv"), "hr", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Hsb => (None, Some("hsb"), "hsb", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ht => (Some("ht"), Some("hat"), "ht", "Latn", LanguageFamily::Creole),
+        ::Language::Hu => (Some("hu"), Some("hun"), "hu", "Latn", LanguageFamily::Uralic),
+        ::Language::Hy => (Some("hy"), Some("hye"), "hy", "Armn", LanguageFamily::IndoEuropean),
+        ::Language::Ia => (Some("ia"), Some("ina"), "ia", "Latn", LanguageFamily::Constructed),
+        ::Language::Id => (Some("id"), Some("ind"), "id", "Latn", LanguageFamily::Austronesian),
+        ::Language::Ie => (Some("ie"), Some("ile"), "ie", "Latn", LanguageFamily::Constructed),
+        ::Language::Ig => (Some("ig"), Some("ibo"), "ig", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Ilo => (None, Some("ilo"), "ilo", "Latn", LanguageFamily::Austronesian),
+        ::Language::Inh => (None, Some("inh"), "inh", "Cyrl", LanguageFamily::NortheastCaucasian),
+        ::Language::Io => (Some("io"), Some("ido"), "io", "Latn", LanguageFamily::Constructed),
+        ::Language::Is => (Some("is"), Some("isl"), "is", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::It => (Some("it"), Some("ita"), "it", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Iu => (Some("iu"), Some("iku"), "iu", "Cans", LanguageFamily::EskimoAleut),
+        ::Language::Izh => (None, Some("izh"), "izh", "Latn", LanguageFamily::Uralic),
+        ::Language::Ja => (Some("ja"), Some("jpn"), "ja", "Jpan", LanguageFamily::Japonic),
+        ::Language::Jbo => (None, Some("jbo"), "jbo", "Latn", LanguageFamily::Constructed),
+        ::Language::Jpa => (None, Some("jpa"), "jpa", "Hebr", LanguageFamily::Afroasiatic),
+        ::Language::Jv => (Some("jv"), Some("jav"), "jv", "Latn", LanguageFamily::Austronesian),
+        ::Language::Ka => (Some("ka"), Some("kat"), "ka", "Geor", LanguageFamily::Kartvelian),
+        ::Language::Kaa => (None, Some("kaa"), "kaa", "Latn", LanguageFamily::Turkic),
+        ::Language::Kab => (None, Some("kab"), "kab", "Latn", LanguageFamily::Afroasiatic),
+        ::Language::Kea => (None, Some("kea"), "kea", "Latn", LanguageFamily::Creole),
+        ::Language::Kim => (None, Some("kim"), "kim", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Kjh => (None, Some("kjh"), "kjh", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Kk => (Some("kk"), Some("kaz"), "kk", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Kl => (Some("kl"), Some("kal"), "kl", "Latn", LanguageFamily::EskimoAleut),
+        ::Language::Km => (Some("km"), Some("khm"), "km", "Khmr", LanguageFamily::Austroasiatic),
+        ::Language::Kn => (Some("kn"), Some("kan"), "kn", "Knda", LanguageFamily::Dravidian),
+        ::Language::Ko => (Some("ko"), Some("kor"), "ko", "Kore", LanguageFamily::Koreanic),
+        ::Language::Kok => (None, Some("kok"), "kok", "Deva", LanguageFamily::IndoEuropean),
+        ::Language::Kos => (None, Some("kos"), "kos", "Latn", LanguageFamily::Austronesian),
+        ::Language::Krl => (None, Some("krl"), "krl", "Latn", LanguageFamily::Uralic),
+        ::Language::Ku => (Some("ku"), Some("kur"), "ku", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Kv => (Some("kv"), Some("kom"), "kv", "Cyrl", LanguageFamily::Uralic),
+        ::Language::Kw => (Some("kw"), Some("cor"), "kw", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ky => (Some("ky"), Some("kir"), "ky", "Cyrl", LanguageFamily::Turkic),
+        ::Language::La => (Some("la"), Some("lat"), "la", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Lad => (None, Some("lad"), "lad", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::LadCyrl => (None, Some("lad"), "lad-Cyrl", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::LadHebr => (None, Some("lad"), "lad-Hebr", "Hebr", LanguageFamily::IndoEuropean),
+        ::Language::LadLatn => (None, Some("lad"), "lad-Latn", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Lb => (Some("lb"), Some("ltz"), "lb", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Lbe => (None, Some("lbe"), "lbe", "Cyrl", LanguageFamily::NortheastCaucasian),
+        ::Language::Lg => (Some("lg"), Some("lug"), "lg", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Li => (Some("li"), Some("lim"), "li", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Lij => (None, Some("lij"), "lij", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Liv => (None, Some("liv"), "liv", "Latn", LanguageFamily::Uralic),
+        ::Language::Lld => (None, Some("lld"), "lld", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Lmo => (None, Some("lmo"), "lmo", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ln => (Some("ln"), Some("lin"), "ln", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Lo => (Some("lo"), Some("lao"), "lo", "Laoo", LanguageFamily::KraDai),
+        ::Language::Lt => (Some("lt"), Some("lit"), "lt", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ltg => (None, Some("ltg"), "ltg", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Lv => (Some("lv"), Some("lav"), "lv", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Mas => (None, Some("mas"), "mas", "Latn", LanguageFamily::NiloSaharan),
+        ::Language::Mdf => (None, Some("mdf"), "mdf", "Cyrl", LanguageFamily::Uralic),
+        ::Language::Mg => (Some("mg"), Some("mlg"), "mg", "Latn", LanguageFamily::Austronesian),
+        ::Language::Mh => (Some("mh"), Some("mah"), "mh", "Latn", LanguageFamily::Austronesian),
+        ::Language::Mi => (Some("mi"), Some("mri"), "mi", "Latn", LanguageFamily::Austronesian),
+        ::Language::Mk => (Some("mk"), Some("mkd"), "mk", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Ml => (Some("ml"), Some("mal"), "ml", "Mlym", LanguageFamily::Dravidian),
+        ::Language::Mn => (Some("mn"), Some("mon"), "mn", "Cyrl", LanguageFamily::Mongolic),
+        ::Language::Mnc => (None, Some("mnc"), "mnc", "Mong", LanguageFamily::Tungusic),
+        ::Language::Mr => (Some("mr"), Some("mar"), "mr", "Deva", LanguageFamily::IndoEuropean),
+        ::Language::Mrh => (None, Some("mrh"), "mrh", "Latn", LanguageFamily::SinoTibetan),
+        ::Language::Ms => (Some("ms"), Some("msa"), "ms", "Latn", LanguageFamily::Austronesian),
+        ::Language::Mt => (Some("mt"), Some("mlt"), "mt", "Latn", LanguageFamily::Afroasiatic),
+        ::Language::Mwl => (None, Some("mwl"), "mwl", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Mww => (None, Some("mww"), "mww", "Latn", LanguageFamily::HmongMien),
+        ::Language::My => (Some("my"), Some("mya"), "my", "Mymr", LanguageFamily::SinoTibetan),
+        ::Language::Myv => (None, Some("myv"), "myv", "Cyrl", LanguageFamily::Uralic),
+        ::Language::Na => (Some("na"), Some("nau"), "na", "Latn", LanguageFamily::Austronesian),
+        ::Language::Nah => (None, None, "nah", "Latn", LanguageFamily::UtoAztecan),
+        ::Language::Nan => (None, Some("nan"), "nan", "Hani", LanguageFamily::SinoTibetan),
+        ::Language::Nap => (None, Some("nap"), "nap", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Nb => (Some("nb"), Some("nob"), "nb", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Nci => (None, Some("nci"), "nci", "Latn", LanguageFamily::UtoAztecan),
+        ::Language::Nds => (None, Some("nds"), "nds", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ne => (Some("ne"), Some("nep"), "ne", "Deva", LanguageFamily::IndoEuropean),
+        ::Language::Ng => (Some("ng"), Some("ndo"), "ng", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Nij => (None, Some("nij"), "nij", "Latn", LanguageFamily::Austronesian),
+        ::Language::Niu => (None, Some("niu"), "niu", "Latn", LanguageFamily::Austronesian),
+        ::Language::Nl => (Some("nl"), Some("nld"), "nl", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Nn => (Some("nn"), Some("nno"), "nn", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::No => (Some("no"), Some("nor"), "no", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Nov => (None, Some("nov"), "nov", "Latn", LanguageFamily::Constructed),
+        ::Language::Nrf => (None, Some("nrf"), "nrf", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Nso => (None, Some("nso"), "nso", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Nv => (Some("nv"), Some("nav"), "nv", "Latn", LanguageFamily::NaDene),
+        ::Language::Ny => (Some("ny"), Some("nya"), "ny", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Oc => (Some("oc"), Some("oci"), "oc", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Oj => (Some("oj"), Some("oji"), "oj", "Latn", LanguageFamily::Algic),
+        ::Language::Om => (Some("om"), Some("orm"), "om", "Latn", LanguageFamily::Afroasiatic),
+        ::Language::Or => (Some("or"), Some("ori"), "or", "Orya", LanguageFamily::IndoEuropean),
+        ::Language::Os => (Some("os"), Some("oss"), "os", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Pa => (Some("pa"), Some("pan"), "pa", "Guru", LanguageFamily::IndoEuropean),
+        ::Language::Pam => (None, Some("pam"), "pam", "Latn", LanguageFamily::Austronesian),
+        ::Language::Pap => (None, Some("pap"), "pap", "Latn", LanguageFamily::Creole),
+        ::Language::Pau => (None, Some("pau"), "pau", "Latn", LanguageFamily::Austronesian),
+        ::Language::Pl => (Some("pl"), Some("pol"), "pl", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Pms => (None, Some("pms"), "pms", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Pnb => (None, Some("pnb"), "pnb", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Pon => (None, Some("pon"), "pon", "Latn", LanguageFamily::Austronesian),
+        ::Language::Pox => (None, Some("pox"), "pox", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Prg => (None, Some("prg"), "prg", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ps => (Some("ps"), Some("pus"), "ps", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Pt => (Some("pt"), Some("por"), "pt", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Qu => (Some("qu"), Some("que"), "qu", "Latn", LanguageFamily::Quechuan),
+        ::Language::Rap => (None, Some("rap"), "rap", "Latn", LanguageFamily::Austronesian),
+        ::Language::Rar => (None, Some("rar"), "rar", "Latn", LanguageFamily::Austronesian),
+        ::Language::Rm => (Some("rm"), Some("roh"), "rm", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Rmy => (None, Some("rmy"), "rmy", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Rn => (Some("rn"), Some("run"), "rn", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Ro => (Some("ro"), Some("ron"), "ro", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Rom => (None, Some("rom"), "rom", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Rtm => (None, Some("rtm"), "rtm", "Latn", LanguageFamily::Austronesian),
+        ::Language::Ru => (Some("ru"), Some("rus"), "ru", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Rue => (None, Some("rue"), "rue", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Rup => (None, Some("rup"), "rup", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Rw => (Some("rw"), Some("kin"), "rw", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Ryu => (None, Some("ryu"), "ryu", "Jpan", LanguageFamily::Japonic),
+        ::Language::Sa => (Some("sa"), Some("san"), "sa", "Deva", LanguageFamily::IndoEuropean),
+        ::Language::Sah => (None, Some("sah"), "sah", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Sc => (Some("sc"), Some("srd"), "sc", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Scn => (None, Some("scn"), "scn", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sco => (None, Some("sco"), "sco", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sd => (Some("sd"), Some("snd"), "sd", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Se => (Some("se"), Some("sme"), "se", "Latn", LanguageFamily::Uralic),
+        ::Language::Sgd => (None, Some("sgd"), "sgd", "Latn", LanguageFamily::Austronesian),
+        ::Language::Sgs => (None, Some("sgs"), "sgs", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sh => (Some("sh"), Some("hbs"), "sh", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Si => (Some("si"), Some("sin"), "si", "Sinh", LanguageFamily::IndoEuropean),
+        ::Language::Sk => (Some("sk"), Some("slk"), "sk", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sl => (Some("sl"), Some("slv"), "sl", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sli => (None, Some("sli"), "sli", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sm => (Some("sm"), Some("smo"), "sm", "Latn", LanguageFamily::Austronesian),
+        ::Language::Sn => (Some("sn"), Some("sna"), "sn", "Latn", LanguageFamily::NigerCongo),
+        ::Language::So => (Some("so"), Some("som"), "so", "Latn", LanguageFamily::Afroasiatic),
+        ::Language::Sq => (Some("sq"), Some("sqi"), "sq", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sr => (Some("sr"), Some("srp"), "sr", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::SrCyrl => (Some("sr"), Some("srp"), "sr-Cyrl", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::SrLatn => (Some("sr"), Some("srp"), "sr-Latn", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Src => (None, Some("src"), "src", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sro => (None, Some("sro"), "sro", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ss => (Some("ss"), Some("ssw"), "ss", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Su => (Some("su"), Some("sun"), "su", "Latn", LanguageFamily::Austronesian),
+        ::Language::Sux => (None, Some("sux"), "sux", "Xsux", LanguageFamily::Isolate),
+        ::Language::Sv => (Some("sv"), Some("swe"), "sv", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Sw => (Some("sw"), Some("swa"), "sw", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Syr => (None, Some("syr"), "syr", "Syrc", LanguageFamily::Afroasiatic),
+        ::Language::Szl => (None, Some("szl"), "szl", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Ta => (Some("ta"), Some("tam"), "ta", "Taml", LanguageFamily::Dravidian),
+        ::Language::Tah => (Some("ty"), Some("tah"), "ty", "Latn", LanguageFamily::Austronesian),
+        ::Language::Te => (Some("te"), Some("tel"), "te", "Telu", LanguageFamily::Dravidian),
+        ::Language::Tg => (Some("tg"), Some("tgk"), "tg", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Th => (Some("th"), Some("tha"), "th", "Thai", LanguageFamily::KraDai),
+        ::Language::Ti => (Some("ti"), Some("tir"), "ti", "Ethi", LanguageFamily::Afroasiatic),
+        ::Language::Tk => (Some("tk"), Some("tuk"), "tk", "Latn", LanguageFamily::Turkic),
+        ::Language::Tkl => (None, Some("tkl"), "tkl", "Latn", LanguageFamily::Austronesian),
+        ::Language::Tl => (Some("tl"), Some("tgl"), "tl", "Latn", LanguageFamily::Austronesian),
+        ::Language::Tlh => (None, Some("tlh"), "tlh", "Latn", LanguageFamily::Constructed),
+        ::Language::Tmr => (None, Some("tmr"), "tmr", "Hebr", LanguageFamily::Afroasiatic),
+        ::Language::Tn => (Some("tn"), Some("tsn"), "tn", "Latn", LanguageFamily::NigerCongo),
+        ::Language::To => (Some("to"), Some("ton"), "to", "Latn", LanguageFamily::Austronesian),
+        ::Language::Tog => (None, Some("tog"), "tog", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Tr => (Some("tr"), Some("tur"), "tr", "Latn", LanguageFamily::Turkic),
+        ::Language::Ts => (Some("ts"), Some("tso"), "ts", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Tt => (Some("tt"), Some("tat"), "tt", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Tum => (None, Some("tum"), "tum", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Tvl => (None, Some("tvl"), "tvl", "Latn", LanguageFamily::Austronesian),
+        ::Language::Tw => (Some("tw"), Some("twi"), "tw", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Ty => (Some("ty"), Some("tah"), "ty", "Latn", LanguageFamily::Austronesian),
+        ::Language::Tyv => (None, Some("tyv"), "tyv", "Cyrl", LanguageFamily::Turkic),
+        ::Language::Udm => (None, Some("udm"), "udm", "Cyrl", LanguageFamily::Uralic),
+        ::Language::Ug => (Some("ug"), Some("uig"), "ug", "Arab", LanguageFamily::Turkic),
+        ::Language::Uk => (Some("uk"), Some("ukr"), "uk", "Cyrl", LanguageFamily::IndoEuropean),
+        ::Language::Ur => (Some("ur"), Some("urd"), "ur", "Arab", LanguageFamily::IndoEuropean),
+        ::Language::Uz => (Some("uz"), Some("uzb"), "uz", "Latn", LanguageFamily::Turkic),
+        ::Language::Vec => (None, Some("vec"), "vec", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Vep => (None, Some("vep"), "vep", "Latn", LanguageFamily::Uralic),
+        ::Language::Vi => (Some("vi"), Some("vie"), "vi", "Latn", LanguageFamily::Austroasiatic),
+        ::Language::Vo => (Some("vo"), Some("vol"), "vo", "Latn", LanguageFamily::Constructed),
+        ::Language::Vot => (None, Some("vot"), "vot", "Latn", LanguageFamily::Uralic),
+        ::Language::Vro => (None, Some("vro"), "vro", "Latn", LanguageFamily::Uralic),
+        ::Language::Wa => (Some("wa"), Some("wln"), "wa", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Wah => (None, Some("wah"), "wah", "Latn", LanguageFamily::Austronesian),
+        ::Language::War => (None, Some("war"), "war", "Latn", LanguageFamily::Austronesian),
+        ::Language::Wo => (Some("wo"), Some("wol"), "wo", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Wym => (None, Some("wym"), "wym", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Xal => (None, Some("xal"), "xal", "Cyrl", LanguageFamily::Mongolic),
+        ::Language::Xcu => (None, Some("xcu"), "xcu", "Latn", LanguageFamily::IndoEuropean),
+        ::Language::Xh => (Some("xh"), Some("xho"), "xh", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Xmf => (None, Some("xmf"), "xmf", "Geor", LanguageFamily::Kartvelian),
+        ::Language::Yi => (Some("yi"), Some("yid"), "yi", "Hebr", LanguageFamily::IndoEuropean),
+        ::Language::Yo => (Some("yo"), Some("yor"), "yo", "Latn", LanguageFamily::NigerCongo),
+        ::Language::Yue => (None, Some("yue"), "yue", "Hani", LanguageFamily::SinoTibetan),
+        ::Language::Zh => (Some("zh"), Some("zho"), "zh", "Hani", LanguageFamily::SinoTibetan),
+        ::Language::Zu => (Some("zu"), Some("zul"), "zu", "Latn", LanguageFamily::NigerCongo),
+    }
+}
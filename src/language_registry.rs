@@ -0,0 +1,56 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use std::{collections::HashMap, sync::Arc};
+
+/// Languages registered at runtime in addition to the languages known by the parser, each with a name and a language code.
+///
+/// Registered languages are recognized in the headings of sections for languages and in the parameters of the template [`Překlady`](https://cs.wiktionary.org/wiki/%C5%A0ablona:P%C5%99eklady). A known language takes precedence over a registered language with the same name or code. Cloning a registry is cheap, because the languages are shared until the clone is modified.
+#[derive(Clone, Debug, Default)]
+pub struct LanguageRegistry {
+    codes: Arc<HashMap<String, String>>,
+    names: Arc<HashMap<String, String>>,
+}
+
+impl LanguageRegistry {
+    /// Creates a registry without any languages.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns whether no languages are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Returns the language code of the registered language with the name `name`, if any.
+    #[must_use]
+    pub fn language_code(&self, name: &str) -> Option<&str> {
+        self.names.get(name).map(String::as_str)
+    }
+
+    /// Returns the name of the registered language with the language code `language_code`, if any.
+    #[must_use]
+    pub fn name(&self, language_code: &str) -> Option<&str> {
+        self.codes.get(language_code).map(String::as_str)
+    }
+
+    /// Registers a language with the name `name`, as written in the headings of sections for languages, and the language code `language_code`, as used in the parameters of templates.
+    ///
+    /// Any language previously registered with the same name or language code is replaced.
+    pub fn register(&mut self, name: &str, language_code: &str) {
+        let codes = Arc::make_mut(&mut self.codes);
+        let names = Arc::make_mut(&mut self.names);
+        if let Some(old_code) = names.insert(name.to_string(), language_code.to_string()) {
+            codes.remove(&old_code);
+        }
+        if let Some(old_name) = codes.insert(language_code.to_string(), name.to_string()) {
+            if old_name != name {
+                names.remove(&old_name);
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
+use std::{fmt, str::FromStr};
+
 /// Identifier for a language.
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -320,6 +322,322 @@ pub enum Language {
 }
 
 impl Language {
+    /// Returns all languages.
+    pub fn all() -> &'static [Self] {
+        &[
+            Language::Aa,
+            Language::Aau,
+            Language::Ab,
+            Language::Abe,
+            Language::Ady,
+            Language::Af,
+            Language::Ain,
+            Language::Ak,
+            Language::Ale,
+            Language::Als,
+            Language::Alt,
+            Language::Am,
+            Language::An,
+            Language::Ang,
+            Language::Ar,
+            Language::Arc,
+            Language::Arn,
+            Language::Arz,
+            Language::As,
+            Language::Ast,
+            Language::Atv,
+            Language::Ay,
+            Language::Az,
+            Language::Azb,
+            Language::Ba,
+            Language::Bal,
+            Language::Bar,
+            Language::Bcl,
+            Language::Be,
+            Language::Bem,
+            Language::Bg,
+            Language::Bi,
+            Language::Bjn,
+            Language::Bm,
+            Language::Bn,
+            Language::Bo,
+            Language::Bpy,
+            Language::Br,
+            Language::Bs,
+            Language::Bxr,
+            Language::Ca,
+            Language::Cab,
+            Language::Ce,
+            Language::Ceb,
+            Language::Ch,
+            Language::Chk,
+            Language::Chp,
+            Language::Chr,
+            Language::Cim,
+            Language::Ckb,
+            Language::Co,
+            Language::Cpf,
+            Language::Cps,
+            Language::Crh,
+            Language::Cs,
+            Language::Csb,
+            Language::Cu,
+            Language::Cv,
+            Language::Cy,
+            Language::Da,
+            Language::De,
+            Language::Dgd,
+            Language::Dje,
+            Language::Dlm,
+            Language::Dsb,
+            Language::Dv,
+            Language::Dz,
+            Language::Ee,
+            Language::Egy,
+            Language::El,
+            Language::En,
+            Language::Eo,
+            Language::Es,
+            Language::Et,
+            Language::Eu,
+            Language::Ext,
+            Language::Fa,
+            Language::Fi,
+            Language::Fil,
+            Language::Fj,
+            Language::Fkv,
+            Language::Fo,
+            Language::Fr,
+            Language::Frp,
+            Language::Frr,
+            Language::Fur,
+            Language::Fy,
+            Language::Ga,
+            Language::Gad,
+            Language::Gag,
+            Language::Gd,
+            Language::Gil,
+            Language::Gl,
+            Language::Gmh,
+            Language::Gml,
+            Language::Gn,
+            Language::Got,
+            Language::Grc,
+            Language::Gsw,
+            Language::Gu,
+            Language::Guz,
+            Language::Gv,
+            Language::Ha,
+            Language::Hak,
+            Language::Haw,
+            Language::He,
+            Language::Hi,
+            Language::Hil,
+            Language::Hit,
+            Language::Hnd,
+            Language::Hr,
+            Language::Hsb,
+            Language::Ht,
+            Language::Hu,
+            Language::Hy,
+            Language::Ia,
+            Language::Id,
+            Language::Ie,
+            Language::Ig,
+            Language::Ilo,
+            Language::Inh,
+            Language::Io,
+            Language::Is,
+            Language::It,
+            Language::Iu,
+            Language::Izh,
+            Language::Ja,
+            Language::Jbo,
+            Language::Jpa,
+            Language::Jv,
+            Language::Ka,
+            Language::Kaa,
+            Language::Kab,
+            Language::Kea,
+            Language::Kim,
+            Language::Kjh,
+            Language::Kk,
+            Language::Kl,
+            Language::Km,
+            Language::Kn,
+            Language::Ko,
+            Language::Kok,
+            Language::Kos,
+            Language::Krl,
+            Language::Ku,
+            Language::Kv,
+            Language::Kw,
+            Language::Ky,
+            Language::La,
+            Language::Lad,
+            Language::LadCyrl,
+            Language::LadHebr,
+            Language::LadLatn,
+            Language::Lb,
+            Language::Lbe,
+            Language::Lg,
+            Language::Li,
+            Language::Lij,
+            Language::Liv,
+            Language::Lld,
+            Language::Lmo,
+            Language::Ln,
+            Language::Lo,
+            Language::Lt,
+            Language::Ltg,
+            Language::Lv,
+            Language::Mas,
+            Language::Mdf,
+            Language::Mg,
+            Language::Mh,
+            Language::Mi,
+            Language::Mk,
+            Language::Ml,
+            Language::Mn,
+            Language::Mnc,
+            Language::Mr,
+            Language::Mrh,
+            Language::Ms,
+            Language::Mt,
+            Language::Mwl,
+            Language::Mww,
+            Language::My,
+            Language::Myv,
+            Language::Na,
+            Language::Nah,
+            Language::Nan,
+            Language::Nap,
+            Language::Nb,
+            Language::Nci,
+            Language::Nds,
+            Language::Ne,
+            Language::Ng,
+            Language::Nij,
+            Language::Niu,
+            Language::Nl,
+            Language::Nn,
+            Language::No,
+            Language::Nov,
+            Language::Nrf,
+            Language::Nso,
+            Language::Nv,
+            Language::Ny,
+            Language::Oc,
+            Language::Oj,
+            Language::Om,
+            Language::Or,
+            Language::Os,
+            Language::Pa,
+            Language::Pam,
+            Language::Pap,
+            Language::Pau,
+            Language::Pl,
+            Language::Pms,
+            Language::Pnb,
+            Language::Pon,
+            Language::Pox,
+            Language::Prg,
+            Language::Ps,
+            Language::Pt,
+            Language::Qu,
+            Language::Rap,
+            Language::Rar,
+            Language::Rm,
+            Language::Rmy,
+            Language::Rn,
+            Language::Ro,
+            Language::Rom,
+            Language::Rtm,
+            Language::Ru,
+            Language::Rue,
+            Language::Rup,
+            Language::Rw,
+            Language::Ryu,
+            Language::Sa,
+            Language::Sah,
+            Language::Sc,
+            Language::Scn,
+            Language::Sco,
+            Language::Sd,
+            Language::Se,
+            Language::Sgd,
+            Language::Sgs,
+            Language::Sh,
+            Language::Si,
+            Language::Sk,
+            Language::Sl,
+            Language::Sli,
+            Language::Sm,
+            Language::Sn,
+            Language::So,
+            Language::Sq,
+            Language::Sr,
+            Language::SrCyrl,
+            Language::SrLatn,
+            Language::Src,
+            Language::Sro,
+            Language::Ss,
+            Language::Su,
+            Language::Sux,
+            Language::Sv,
+            Language::Sw,
+            Language::Syr,
+            Language::Szl,
+            Language::Ta,
+            Language::Tah,
+            Language::Te,
+            Language::Tg,
+            Language::Th,
+            Language::Ti,
+            Language::Tk,
+            Language::Tkl,
+            Language::Tl,
+            Language::Tlh,
+            Language::Tmr,
+            Language::Tn,
+            Language::To,
+            Language::Tog,
+            Language::Tr,
+            Language::Ts,
+            Language::Tt,
+            Language::Tum,
+            Language::Tvl,
+            Language::Tw,
+            Language::Ty,
+            Language::Tyv,
+            Language::Udm,
+            Language::Ug,
+            Language::Uk,
+            Language::Ur,
+            Language::Uz,
+            Language::Vec,
+            Language::Vep,
+            Language::Vi,
+            Language::Vo,
+            Language::Vot,
+            Language::Vro,
+            Language::Wa,
+            Language::Wah,
+            Language::War,
+            Language::Wo,
+            Language::Wym,
+            Language::Xal,
+            Language::Xcu,
+            Language::Xh,
+            Language::Xmf,
+            Language::Yi,
+            Language::Yo,
+            Language::Yue,
+            Language::Zh,
+            Language::Zu,
+        ]
+    }
+
     /// Returns the language corresponding to the given language code if any.
     pub fn from_language_code(language_code: &str) -> Option<Self> {
         Some(match language_code {
@@ -895,6 +1213,7 @@ impl Language {
             "srbština (cyrilice)" => Language::SrCyrl,
             "srbština (latinka)" => Language::SrLatn,
             "lugudorská sardinština" => Language::Src,
+            "kampidánská sardinština" => Language::Sro,
             "siswatština" => Language::Ss,
             "sundánština" => Language::Su,
             "sumerština" => Language::Sux,
@@ -1267,4 +1586,352 @@ impl Language {
             Language::Zu => "zu",
         }
     }
+
+    /// Returns the name of the language, as used in the headings of language sections.
+    ///
+    /// The language `Tah` is an alternative code for the same language as `Ty`, and has the same name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Aa => "afarština",
+            Language::Aau => "abauština",
+            Language::Ab => "abcházština",
+            Language::Abe => "abenakština",
+            Language::Ady => "adygejština",
+            Language::Af => "afrikánština",
+            Language::Ain => "ainština",
+            Language::Ak => "akanština",
+            Language::Ale => "aleutština",
+            Language::Als => "alsaština",
+            Language::Alt => "jižní altajština",
+            Language::Am => "amharština",
+            Language::An => "aragonština",
+            Language::Ang => "staroangličtina",
+            Language::Ar => "arabština",
+            Language::Arc => "aramejština",
+            Language::Arn => "araukánština",
+            Language::Arz => "egyptská arabština",
+            Language::As => "asámština",
+            Language::Ast => "asturština",
+            Language::Atv => "severní altajština",
+            Language::Ay => "ajmarština",
+            Language::Az => "ázerbájdžánština",
+            Language::Azb => "jižní ázerbájdžánština",
+            Language::Ba => "baškirština",
+            Language::Bal => "balúčština",
+            Language::Bar => "bavorština",
+            Language::Bcl => "bikolanština",
+            Language::Be => "běloruština",
+            Language::Bem => "bembština",
+            Language::Bg => "bulharština",
+            Language::Bi => "bislamština",
+            Language::Bjn => "bandžárština",
+            Language::Bm => "bambarština",
+            Language::Bn => "bengálština",
+            Language::Bo => "tibetština",
+            Language::Bpy => "bišnuprijskomanipurština",
+            Language::Br => "bretonština",
+            Language::Bs => "bosenština",
+            Language::Bxr => "burjatština",
+            Language::Ca => "katalánština",
+            Language::Cab => "garífuna",
+            Language::Ce => "čečenština",
+            Language::Ceb => "cebuánština",
+            Language::Ch => "čamorština",
+            Language::Chk => "čukština",
+            Language::Chp => "čipevajština",
+            Language::Chr => "čerokézština",
+            Language::Cim => "cimbriština",
+            Language::Ckb => "středokurdština",
+            Language::Co => "korsičtina",
+            Language::Cpf => "francouzská kreolština",
+            Language::Cps => "kapiznonština",
+            Language::Crh => "krymská tatarština",
+            Language::Cs => "čeština",
+            Language::Csb => "kašubština",
+            Language::Cu => "staroslověnština",
+            Language::Cv => "čuvaština",
+            Language::Cy => "velština",
+            Language::Da => "dánština",
+            Language::De => "němčina",
+            Language::Dgd => "dagaari dioulou",
+            Language::Dje => "zarmština",
+            Language::Dlm => "dalmatština",
+            Language::Dsb => "dolnolužická srbština",
+            Language::Dv => "divehi (maledivština)",
+            Language::Dz => "dzongkä",
+            Language::Ee => "eveština",
+            Language::Egy => "starověká egyptština",
+            Language::El => "řečtina",
+            Language::En => "angličtina",
+            Language::Eo => "esperanto",
+            Language::Es => "španělština",
+            Language::Et => "estonština",
+            Language::Eu => "baskičtina",
+            Language::Ext => "extremadurština",
+            Language::Fa => "perština",
+            Language::Fi => "finština",
+            Language::Fil => "filipínština",
+            Language::Fj => "fidžijština",
+            Language::Fkv => "kvenština",
+            Language::Fo => "faerština",
+            Language::Fr => "francouzština",
+            Language::Frp => "franko-provensálština",
+            Language::Frr => "severofríština",
+            Language::Fur => "furlandština",
+            Language::Fy => "fríština",
+            Language::Ga => "irština",
+            Language::Gad => "gaddang",
+            Language::Gag => "gagauzština",
+            Language::Gd => "skotská gaelština",
+            Language::Gil => "kiribatština",
+            Language::Gl => "galicijština",
+            Language::Gmh => "střední horní němčina",
+            Language::Gml => "střední dolní němčina",
+            Language::Gn => "guaranština",
+            Language::Got => "gótština",
+            Language::Grc => "starořečtina",
+            Language::Gsw => "švýcarská němčina",
+            Language::Gu => "gudžarátština",
+            Language::Guz => "gusiština",
+            Language::Gv => "manština",
+            Language::Ha => "hauština",
+            Language::Hak => "hakka",
+            Language::Haw => "havajština",
+            Language::He => "hebrejština",
+            Language::Hi => "hindština",
+            Language::Hil => "hiligajnonština",
+            Language::Hit => "chetitština",
+            Language::Hnd => "hindko",
+            Language::Hr => "chorvatština",
+            Language::Hsb => "hornolužická srbština",
+            Language::Ht => "haitština",
+            Language::Hu => "maďarština",
+            Language::Hy => "arménština",
+            Language::Ia => "interlingua",
+            Language::Id => "indonéština",
+            Language::Ie => "interlingue",
+            Language::Ig => "igboština",
+            Language::Ilo => "ilokánština",
+            Language::Inh => "inguština",
+            Language::Io => "ido",
+            Language::Is => "islandština",
+            Language::It => "italština",
+            Language::Iu => "inuktitutština",
+            Language::Izh => "ingrijština",
+            Language::Ja => "japonština",
+            Language::Jbo => "lojban",
+            Language::Jpa => "židovská palestinská aramejština",
+            Language::Jv => "javánština",
+            Language::Ka => "gruzínština",
+            Language::Kaa => "karakalpačtina",
+            Language::Kab => "kabylština",
+            Language::Kea => "kapverdská kreolština",
+            Language::Kim => "tofalarština",
+            Language::Kjh => "chakaština",
+            Language::Kk => "kazaština",
+            Language::Kl => "grónština",
+            Language::Km => "khmerština",
+            Language::Kn => "kannadština",
+            Language::Ko => "korejština",
+            Language::Kok => "konkánština",
+            Language::Kos => "kosrajština",
+            Language::Krl => "karelština",
+            Language::Ku => "kurdština",
+            Language::Kv => "komijština",
+            Language::Kw => "kornština",
+            Language::Ky => "kyrgyzština",
+            Language::La => "latina",
+            Language::Lad => "ladino",
+            Language::LadCyrl => "ladino (cyrilice)",
+            Language::LadHebr => "ladino (hebrejské písmo)",
+            Language::LadLatn => "ladino (latinka)",
+            Language::Lb => "lucemburština",
+            Language::Lbe => "lakština",
+            Language::Lg => "lugandština",
+            Language::Li => "limburština",
+            Language::Lij => "ligurština",
+            Language::Liv => "livonština",
+            Language::Lld => "ladinština",
+            Language::Lmo => "lombardština",
+            Language::Ln => "lingalština",
+            Language::Lo => "laoština",
+            Language::Lt => "litevština",
+            Language::Ltg => "latgalština",
+            Language::Lv => "lotyština",
+            Language::Mas => "masajština",
+            Language::Mdf => "mokša",
+            Language::Mg => "malgaština",
+            Language::Mh => "maršálština",
+            Language::Mi => "maorština",
+            Language::Mk => "makedonština",
+            Language::Ml => "malajálamština",
+            Language::Mn => "mongolština",
+            Language::Mnc => "mandžuština",
+            Language::Mr => "maráthština",
+            Language::Mrh => "mara",
+            Language::Ms => "malajština",
+            Language::Mt => "maltština",
+            Language::Mwl => "mirandština",
+            Language::Mww => "bíla hmongština",
+            Language::My => "barmština",
+            Language::Myv => "erzja",
+            Language::Na => "naurština",
+            Language::Nah => "aztéčtina",
+            Language::Nan => "jižní min",
+            Language::Nap => "neapolština",
+            Language::Nb => "norština (bokmål)",
+            Language::Nci => "klasický nahuatl",
+            Language::Nds => "dolnoněmčina",
+            Language::Ne => "nepálština",
+            Language::Ng => "ndondština",
+            Language::Nij => "ngadžuština",
+            Language::Niu => "niueština",
+            Language::Nl => "nizozemština",
+            Language::Nn => "norština (nynorsk)",
+            Language::No => "norština",
+            Language::Nov => "novial",
+            Language::Nrf => "guernseyština",
+            Language::Nso => "severní sotština",
+            Language::Nv => "navaho",
+            Language::Ny => "čičevština",
+            Language::Oc => "okcitánština",
+            Language::Oj => "odžibvejština",
+            Language::Om => "oromo",
+            Language::Or => "urijština",
+            Language::Os => "osetština",
+            Language::Pa => "pandžábština",
+            Language::Pam => "pampangau",
+            Language::Pap => "papiamentština",
+            Language::Pau => "palauština",
+            Language::Pl => "polština",
+            Language::Pms => "piemontština",
+            Language::Pnb => "paňdžábština (shahmukhi)",
+            Language::Pon => "pohnpeiština",
+            Language::Pox => "polabština",
+            Language::Prg => "pruština",
+            Language::Ps => "paštunština",
+            Language::Pt => "portugalština",
+            Language::Qu => "kečuánština",
+            Language::Rap => "rapanuiština",
+            Language::Rar => "rarotongština",
+            Language::Rm => "rétorománština",
+            Language::Rmy => "vlašská romština",
+            Language::Rn => "kirundi",
+            Language::Ro => "rumunština",
+            Language::Rom => "romština",
+            Language::Rtm => "rotumanština",
+            Language::Ru => "ruština",
+            Language::Rue => "rusínština",
+            Language::Rup => "arumunština",
+            Language::Rw => "rwandština",
+            Language::Ryu => "okinawština",
+            Language::Sa => "sanskrt",
+            Language::Sah => "jakutština",
+            Language::Sc => "sardinština",
+            Language::Scn => "sicilština",
+            Language::Sco => "skotština",
+            Language::Sd => "sindhština",
+            Language::Se => "severní sámština",
+            Language::Sgd => "surigaononština",
+            Language::Sgs => "žemaitština",
+            Language::Sh => "srbochorvatština",
+            Language::Si => "sinhálština",
+            Language::Sk => "slovenština",
+            Language::Sl => "slovinština",
+            Language::Sli => "slezská němčina",
+            Language::Sm => "samojština",
+            Language::Sn => "šonština",
+            Language::So => "somálština",
+            Language::Sq => "albánština",
+            Language::Sr => "srbština",
+            Language::SrCyrl => "srbština (cyrilice)",
+            Language::SrLatn => "srbština (latinka)",
+            Language::Src => "lugudorská sardinština",
+            Language::Sro => "kampidánská sardinština",
+            Language::Ss => "siswatština",
+            Language::Su => "sundánština",
+            Language::Sux => "sumerština",
+            Language::Sv => "švédština",
+            Language::Sw => "svahilština",
+            Language::Syr => "syrština",
+            Language::Szl => "slezština",
+            Language::Ta => "tamilština",
+            Language::Tah => "tahitština",
+            Language::Te => "telugština",
+            Language::Tg => "tádžičtina",
+            Language::Th => "thajština",
+            Language::Ti => "tigrinijština",
+            Language::Tk => "turkmenština",
+            Language::Tkl => "tokelauština",
+            Language::Tl => "tagalština",
+            Language::Tlh => "klingonština",
+            Language::Tmr => "židovská babylonská aramejština",
+            Language::Tn => "setswanština",
+            Language::To => "tongánština",
+            Language::Tog => "malavská tongština",
+            Language::Tr => "turečtina",
+            Language::Ts => "tsonga",
+            Language::Tt => "tatarština",
+            Language::Tum => "tumbučtina",
+            Language::Tvl => "tuvalština",
+            Language::Tw => "ťwiština",
+            Language::Ty => "tahitština",
+            Language::Tyv => "tuvinština",
+            Language::Udm => "udmurtština",
+            Language::Ug => "ujgurština",
+            Language::Uk => "ukrajinština",
+            Language::Ur => "urdština",
+            Language::Uz => "uzbečtina",
+            Language::Vec => "benátština",
+            Language::Vep => "vepština",
+            Language::Vi => "vietnamština",
+            Language::Vo => "volapük",
+            Language::Vot => "votština",
+            Language::Vro => "võruština",
+            Language::Wa => "valonština",
+            Language::Wah => "watubela",
+            Language::War => "warajština",
+            Language::Wo => "wolofština",
+            Language::Wym => "vilamovština",
+            Language::Xal => "kalmyčtina",
+            Language::Xcu => "kurština",
+            Language::Xh => "xhoština",
+            Language::Xmf => "mingrelština",
+            Language::Yi => "jidiš",
+            Language::Yo => "jorubština",
+            Language::Yue => "kantonština",
+            Language::Zh => "čínština",
+            Language::Zu => "zuluština",
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    /// Formats the name of the language.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
 }
+
+impl FromStr for Language {
+    type Err = ParseLanguageError;
+
+    /// Parses a language code or the name of a language.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Language::from_language_code(value)
+            .or_else(|| Language::from_name(value))
+            .ok_or(ParseLanguageError)
+    }
+}
+
+/// Error from parsing a language that is not recognized, neither as a language code nor as the name of a language.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseLanguageError;
+
+impl fmt::Display for ParseLanguageError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("unrecognized language")
+    }
+}
+
+impl ::std::error::Error for ParseLanguageError {}
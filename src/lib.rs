@@ -47,35 +47,83 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+#[cfg(feature = "bzip2")]
+extern crate bzip2;
 extern crate parse_wiki_text;
+extern crate quick_xml;
 extern crate serde;
+extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
 
+pub mod batch;
 mod configuration;
 mod definition;
 mod details;
+pub mod dump;
 mod etymology;
+mod explanation;
 mod external_links;
+mod form_of;
 mod hyphenation;
 pub mod inflection;
 mod inflection_field;
 mod language;
+mod language_metadata;
+mod language_registry;
 mod languages;
+mod location;
+mod notes;
+mod owned;
 mod pos;
 mod pronunciation;
 mod related_terms;
+mod render;
+pub mod report;
 mod section;
 mod templates;
 mod translations;
 mod util;
+mod warning_code;
 
 pub use configuration::create_configuration;
-pub use languages::Language;
+pub use language_metadata::LanguageFamily;
+pub use language_registry::LanguageRegistry;
+pub use languages::{Language, ParseLanguageError};
 use parse_wiki_text::{ListItem, Node, Parameter};
+pub use render::render_wiki_text_warning;
 use section::*;
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
 use util::*;
+pub use warning_code::WarningCode;
+
+/// Animacy of a masculine noun.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Animacy {
+    /// Animate, from `životný`.
+    Animate,
+
+    /// Inanimate, from `neživotný`.
+    Inanimate,
+}
+
+/// Aspect of a verb.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aspect {
+    /// Both perfective and imperfective, from `obouvidový` or `dokonavý i nedokonavý`.
+    Biaspectual,
+
+    /// Imperfective, from `nedokonavý`.
+    Imperfective,
+
+    /// Perfective, from `dokonavý`.
+    Perfective,
+}
 
 /// Audio sample.
 #[derive(Debug, Deserialize, Serialize)]
@@ -92,13 +140,19 @@ pub struct Audio<'a> {
 /// Parsed from a single list item in the section `význam`.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Definition<'a> {
+    /// Sub-definitions of the definition, such as senses 1a and 1b of sense 1.
+    ///
+    /// Parsed from the nested list items starting with `##`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Definition<'a>>,
+
     /// A series of elements to display as the definition.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub definition: Vec<Flowing<'a>>,
 
-    /// List of example sentences belonging to the definition, from the template [`Příklad`](https://cs.wiktionary.org/wiki/%C5%A0ablona:P%C5%99%C3%ADklad).
+    /// List of example sentences belonging to the definition.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub examples: Vec<Cow<'a, str>>,
+    pub examples: Vec<Example<'a>>,
 
     /// List of labels, from the template [`Příznaky`](https://cs.wiktionary.org/wiki/%C5%A0ablona:P%C5%99%C3%ADznaky).
     ///
@@ -109,6 +163,51 @@ pub struct Definition<'a> {
     /// A text to display as a phrase, if any, from the template [`Vazba`](https://cs.wiktionary.org/wiki/%C5%A0ablona:Vazba).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phrase: Option<Cow<'a, str>>,
+
+    /// Indexes in [`Output::references`](struct.Output.html#structfield.references) of the references cited in the definition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<usize>,
+}
+
+/// The language of an entry for a language.
+///
+/// Parsed from the heading of the section for the language. Known languages are serialized as their language code, other languages as an object with the heading and the language code if registered.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum EntryLanguage<'a> {
+    /// A language known by the parser.
+    Known(Language),
+
+    /// A language not known by the parser.
+    Other {
+        /// The language code, if the language is registered in [`ParseOptions::languages`](struct.ParseOptions.html#structfield.languages).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<Cow<'a, str>>,
+
+        /// The heading of the section for the language.
+        name: Cow<'a, str>,
+    },
+}
+
+/// Example sentence, from the template [`Příklad`](https://cs.wiktionary.org/wiki/%C5%A0ablona:P%C5%99%C3%ADklad).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Example<'a> {
+    /// The example sentence.
+    pub example: Cow<'a, str>,
+
+    /// Indexes in [`Output::references`](struct.Output.html#structfield.references) of the references cited for the example.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<usize>,
+}
+
+/// Explanation of a warning for editors of the wiki text, from [`Warning::explanation`](struct.Warning.html#method.explanation).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct Explanation {
+    /// A short description of the problem.
+    pub description: &'static str,
+
+    /// A suggested way to fix the problem.
+    pub fix: &'static str,
 }
 
 /// External link.
@@ -219,6 +318,104 @@ pub enum Flowing<'a> {
     },
 }
 
+/// The lemma and grammatical properties of an inflected form.
+///
+/// Parsed from a definition in a section for word forms, such as `tvar podstatného jména`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FormOf<'a> {
+    /// The lemma of the form, the target of the last link in the definition.
+    pub lemma: Cow<'a, str>,
+
+    /// The grammatical properties of the form, recognized from the words of the definition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<GrammaticalTag>,
+}
+
+/// Grammatical gender.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gender {
+    /// Feminine, from `ženský`.
+    Feminine,
+
+    /// Masculine, from `mužský`.
+    Masculine,
+
+    /// Neuter, from `střední`.
+    Neuter,
+}
+
+/// Grammatical property of an inflected form.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrammaticalTag {
+    /// Accusative case, from `akuzativ` or `4. pád`.
+    Accusative,
+
+    /// Conditional mood, from `podmiňovací způsob` or `kondicionál`.
+    Conditional,
+
+    /// Dative case, from `dativ` or `3. pád`.
+    Dative,
+
+    /// Dual number, from `dvojné číslo` or `duál`.
+    Dual,
+
+    /// First person, from `1. osoba` or `první osoba`.
+    FirstPerson,
+
+    /// Future tense, from `budoucí čas`.
+    Future,
+
+    /// Genitive case, from `genitiv` or `2. pád`.
+    Genitive,
+
+    /// Imperative mood, from `rozkazovací způsob` or `imperativ`.
+    Imperative,
+
+    /// Indicative mood, from `oznamovací způsob` or `indikativ`.
+    Indicative,
+
+    /// Instrumental case, from `instrumentál` or `7. pád`.
+    Instrumental,
+
+    /// Locative case, from `lokál` or `6. pád`.
+    Locative,
+
+    /// Nominative case, from `nominativ` or `1. pád`.
+    Nominative,
+
+    /// Participle, from `příčestí`.
+    Participle,
+
+    /// Passive voice, from `trpný rod` or `pasivum`.
+    Passive,
+
+    /// Past tense, from `minulý čas`.
+    Past,
+
+    /// Plural number, from `množné číslo` or `plurál`.
+    Plural,
+
+    /// Present tense, from `přítomný čas`.
+    Present,
+
+    /// Second person, from `2. osoba` or `druhá osoba`.
+    SecondPerson,
+
+    /// Singular number, from `jednotné číslo` or `singulár`.
+    Singular,
+
+    /// Third person, from `3. osoba` or `třetí osoba`.
+    ThirdPerson,
+
+    /// Transgressive, from `přechodník`.
+    Transgressive,
+
+    /// Vocative case, from `vokativ` or `5. pád`.
+    Vocative,
+}
+
 /// Pattern of inflection.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
@@ -268,6 +465,17 @@ pub struct InflectionEntry<'a> {
     pub inflection: Option<Inflection<'a>>,
 }
 
+/// Language of texts meant for people, such as explanations of warnings.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Locale {
+    /// Czech.
+    Cs,
+
+    /// English.
+    En,
+}
+
 /// Dictionary entry for a single language.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct LanguageEntry<'a> {
@@ -292,7 +500,7 @@ pub struct LanguageEntry<'a> {
     pub ipa: Vec<Cow<'a, str>>,
 
     /// The language of the entry.
-    pub language: Language,
+    pub language: EntryLanguage<'a>,
 
     /// Entries for parts of speech for this language.
     ///
@@ -322,11 +530,61 @@ pub struct Output<'a> {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub language_entries: Vec<LanguageEntry<'a>>,
 
+    /// Notes about the entries on the page.
+    ///
+    /// Parsed from the unordered lists in the section `poznámky`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<Vec<Flowing<'a>>>,
+
+    /// The target of the redirect, if the page is a redirect.
+    ///
+    /// Parsed from the wiki text `#PŘESMĚRUJ [[...]]` or `#REDIRECT [[...]]` at the start of the page. A redirect page has no dictionary entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<Redirect<'a>>,
+
+    /// References cited anywhere on the page, in the order they first occur.
+    ///
+    /// Parsed from the content of the extension tags `ref`. Definitions and examples refer to these by index.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Vec<Flowing<'a>>>,
+
+    /// Titles of related pages, such as pages with the same letters but different capitalization or diacritics.
+    ///
+    /// Parsed from the template [`Viz`](https://cs.wiktionary.org/wiki/%C5%A0ablona:Viz) at the top of the page.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub see_also: Vec<Cow<'a, str>>,
+
+    /// The title of the page, if given when parsing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Cow<'a, str>>,
+
     /// Warnings from the parser telling that something is not well-formed.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<Warning>,
 }
 
+/// Options for [`parse_with_options`](fn.parse_with_options.html).
+#[derive(Clone, Debug)]
+pub struct ParseOptions {
+    /// The kinds of warnings that make parsing fail in strict mode.
+    ///
+    /// By default all kinds of warnings except [`Supplementary`](enum.WarningMessage.html#variant.Supplementary), which doesn't indicate an error.
+    pub fatal_warnings: HashSet<WarningMessage>,
+
+    /// Languages to recognize in addition to the languages known by the parser.
+    ///
+    /// By default empty.
+    pub languages: LanguageRegistry,
+
+    /// Whether to return an error instead of the output when any warning of a kind in `fatal_warnings` occurs.
+    pub strict: bool,
+
+    /// The codes of warnings to leave out of the output. Suppressed warnings don't make parsing fail in strict mode.
+    ///
+    /// By default empty.
+    pub suppressed_warnings: HashSet<WarningCode>,
+}
+
 /// Part of speech.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -346,6 +604,11 @@ pub enum Pos {
     /// Parsed from the section `příslovce` and similar numbered sections.
     Adverb,
 
+    /// Character.
+    ///
+    /// Parsed from the section `znak` and similar numbered sections.
+    Character,
+
     /// Compound word.
     ///
     /// Parsed from the section `slovní spojení` and similar numbered sections.
@@ -356,6 +619,11 @@ pub enum Pos {
     /// Parsed from the section `spojka` and similar numbered sections.
     Conjunction,
 
+    /// Fixed expression.
+    ///
+    /// Parsed from the section `ustálené spojení` and similar numbered sections.
+    FixedExpression,
+
     /// Idiom.
     ///
     /// Parsed from the section `idiom` and similar numbered sections.
@@ -381,6 +649,11 @@ pub enum Pos {
     /// Parsed from the section `částice` and similar numbered sections.
     Particle,
 
+    /// Phrase.
+    ///
+    /// Parsed from the section `fráze` and similar numbered sections.
+    Phrase,
+
     /// Prefix.
     ///
     /// Parsed from the section `předpona` and similar numbered sections.
@@ -396,6 +669,11 @@ pub enum Pos {
     /// Parsed from the section `zájmeno` and similar numbered sections.
     Pronoun,
 
+    /// Proper noun.
+    ///
+    /// Parsed from the section `vlastní jméno` and similar numbered sections.
+    ProperNoun,
+
     /// Proverb.
     ///
     /// Parsed from the sections `přísloví` and `rčení` and similar numbered sections.
@@ -406,6 +684,11 @@ pub enum Pos {
     /// Parsed from the section `přípona` and similar numbered sections.
     Suffix,
 
+    /// Symbol.
+    ///
+    /// Parsed from the section `značka` and similar numbered sections.
+    Symbol,
+
     /// Verb.
     ///
     /// Parsed from the section `sloveso` and similar numbered sections.
@@ -415,12 +698,26 @@ pub enum Pos {
 /// The entry for a part of speech within the entry for a language.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PosEntry<'a> {
+    /// The animacy of the entry, if given in the details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animacy: Option<Animacy>,
+
     /// Antonyms for the entry.
     ///
     /// Parsed from the section `antonyma`.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub antonyms: Vec<Vec<Flowing<'a>>>,
 
+    /// The aspect of the entry, if given in the details or otherwise in the conjugation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aspect: Option<Aspect>,
+
+    /// Verbs of the opposite aspect with the same meaning.
+    ///
+    /// Parsed from the line `vidový protějšek` in the details.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aspect_counterparts: Vec<Cow<'a, str>>,
+
     /// Compound words for the entry.
     ///
     /// Parsed from the section `slovní spojení`.
@@ -435,7 +732,7 @@ pub struct PosEntry<'a> {
 
     /// Various details about the entry.
     ///
-    /// Parsed from the unordered list between the POS heading and the next heading
+    /// Parsed from the unordered list between the POS heading and the next heading. Details that are recognized are also available in [`animacy`](#structfield.animacy), [`aspect`](#structfield.aspect), [`gender`](#structfield.gender) and [`transitivity`](#structfield.transitivity).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<Vec<Flowing<'a>>>,
 
@@ -443,6 +740,28 @@ pub struct PosEntry<'a> {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub etymology: Vec<Flowing<'a>>,
 
+    /// Whether the entry is an inflected form of another word rather than a lemma.
+    ///
+    /// Set for sections for word forms, such as `tvar podstatného jména` and `tvar slovesa`.
+    #[serde(default, skip_serializing_if = "::std::ops::Not::not")]
+    pub form: bool,
+
+    /// The lemmas and grammatical properties of the entry as an inflected form, one for each definition that links to a lemma.
+    ///
+    /// Parsed from the definitions in sections for word forms, such as `tvar podstatného jména` and `tvar slovesa`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub form_of: Vec<FormOf<'a>>,
+
+    /// The gender of the entry, if given in the details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+
+    /// The number of the entry among entries for the same part of speech, if any.
+    ///
+    /// Parsed from the number in parentheses in a heading such as `sloveso (2)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homonym_index: Option<usize>,
+
     /// Inflection of the entry, from the sections `časování`, `skloňování`, `skloňování (1)`, `skloňování (2)` and `stupňování`.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub inflection: Vec<InflectionEntry<'a>>,
@@ -476,6 +795,10 @@ pub struct PosEntry<'a> {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub synonyms: Vec<Vec<Flowing<'a>>>,
 
+    /// The transitivity of the entry, if given in the details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transitivity: Option<Transitivity>,
+
     /// Translations for each definition of the entry.
     ///
     /// Parsed from the section 'překlady'.
@@ -489,6 +812,76 @@ pub struct PosEntry<'a> {
     pub variants: Vec<Vec<Flowing<'a>>>,
 }
 
+/// Target of a redirect page.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Redirect<'a> {
+    /// The section of the target page the redirect refers to, if any.
+    ///
+    /// Parsed from the part of the link target after `#`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<Cow<'a, str>>,
+
+    /// The title of the page the redirect refers to.
+    pub target: Cow<'a, str>,
+}
+
+/// Format of a warning rendered by [`Warning::render`](struct.Warning.html#method.render).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RenderFormat {
+    /// Plain text with ANSI escape codes for colors, for printing to a terminal.
+    Ansi,
+
+    /// HTML, with the warning highlighted by the element `mark`, for inserting in a web page.
+    Html,
+
+    /// Plain text without any markup.
+    Plain,
+}
+
+/// Options for [`Warning::render`](struct.Warning.html#method.render).
+#[derive(Clone, Debug)]
+pub struct RenderOptions {
+    /// The number of lines of wiki text to show before and after the lines where the warning occurred.
+    ///
+    /// By default 3.
+    pub context_lines: usize,
+
+    /// The language of an explanation of the warning and a suggested fix to show after the snippet, if any.
+    ///
+    /// By default none.
+    pub explanation: Option<Locale>,
+
+    /// The format of the output.
+    ///
+    /// By default plain text.
+    pub format: RenderFormat,
+}
+
+/// How serious a warning is.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// The wiki text is not well-formed, and information from the element is missing from the output or may be incorrectly parsed.
+    Error,
+
+    /// The wiki text doesn't indicate an error, but contains information that is not represented in the output.
+    Info,
+
+    /// The wiki text is parsed, but is probably incomplete or incorrect, for example an empty element or a value that doesn't match the title of the page.
+    Warning,
+}
+
+/// Transitivity of a verb.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Transitivity {
+    /// Intransitive, from `nepřechodný`.
+    Intransitive,
+
+    /// Transitive, from `přechodný`.
+    Transitive,
+}
+
 /// The translations for a single definition.
 ///
 /// Parsed from the template [Překlady](https://cs.wiktionary.org/wiki/%C5%A0ablona:P%C5%99eklady).
@@ -498,28 +891,134 @@ pub struct Translations<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gloss: Option<Cow<'a, str>>,
 
+    /// The translations to languages registered in [`ParseOptions::languages`](struct.ParseOptions.html#structfield.languages), by language code.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub other_translations: HashMap<Cow<'a, str>, Vec<Flowing<'a>>>,
+
     /// The translations by language.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub translations: HashMap<Language, Vec<Flowing<'a>>>,
 }
 
+impl<'a> EntryLanguage<'a> {
+    /// Returns the language if it's known by the parser.
+    #[must_use]
+    pub fn known(&self) -> Option<Language> {
+        match self {
+            EntryLanguage::Known(language) => Some(*language),
+            EntryLanguage::Other { .. } => None,
+        }
+    }
+
+    /// Returns the language code of the language, if any.
+    #[must_use]
+    pub fn language_code(&self) -> Option<&str> {
+        match self {
+            EntryLanguage::Known(language) => Some(language.language_code()),
+            EntryLanguage::Other { code, .. } => code.as_ref().map(|code| code as _),
+        }
+    }
+
+    /// Returns the name of the language, as written in the heading of the section for the language.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            EntryLanguage::Known(language) => language.name(),
+            EntryLanguage::Other { name, .. } => name,
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            fatal_warnings: [
+                WarningMessage::Duplicate,
+                WarningMessage::Empty,
+                WarningMessage::SectionEmpty,
+                WarningMessage::TitleMismatch,
+                WarningMessage::Unrecognized,
+                WarningMessage::ValueConflicting,
+                WarningMessage::ValueUnrecognized,
+            ]
+                .iter()
+                .cloned()
+                .collect(),
+            languages: LanguageRegistry::new(),
+            strict: false,
+            suppressed_warnings: HashSet::new(),
+        }
+    }
+}
+
+impl<'a> Output<'a> {
+    /// Returns whether any of the warnings has the severity [`Error`](enum.Severity.html#variant.Error).
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.warnings
+            .iter()
+            .any(|warning| warning.severity == Severity::Error)
+    }
+
+    /// Returns the warnings with the severity `severity`.
+    pub fn warnings_with_severity(&self, severity: Severity) -> impl Iterator<Item = &Warning> {
+        self.warnings
+            .iter()
+            .filter(move |warning| warning.severity == severity)
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            context_lines: 3,
+            explanation: None,
+            format: RenderFormat::Plain,
+        }
+    }
+}
+
 /// Warning from the parser telling that something is not well-formed.
 ///
 /// When a warning occurs, it's not guaranteed that the text near the warning is parsed correctly. Usually the data that could not be unambiguously parsed due to the warning is excluded from the output, to make sure the output doesn't contain incorrectly parsed data.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Warning {
+    /// An identifier for the specific kind of problem.
+    pub code: WarningCode,
+
     /// The byte position in the wiki text where the warning ends.
     pub end: usize,
 
-    /// The language of the language section in which the warning occurred, if any.
+    /// The language of the language section in which the warning occurred, if any, including languages not known by the parser.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<Language>,
+    pub language: Option<EntryLanguage<'static>>,
 
-    /// An identifier for the kind of warning.
+    /// An identifier for the kind of warning. Always the kind of warning the code belongs to.
     pub message: WarningMessage,
 
+    /// The name of the template parameter in which the warning occurred, if any.
+    ///
+    /// For parameters without a name, this is the position of the parameter among the parameters without a name, starting from 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+
+    /// The part of speech of the section in which the warning occurred, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos: Option<Pos>,
+
+    /// The heading of the section in which the warning occurred, if any, for example `překlady`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+
+    /// How serious the warning is. Always the same as the severity of the kind of warning.
+    pub severity: Severity,
+
     /// The byte position in the wiki text where the warning starts.
     pub start: usize,
+
+    /// The name of the innermost template in which the warning occurred, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
 }
 
 /// Identifier for a kind of warning from the parser.
@@ -539,9 +1038,14 @@ pub enum WarningMessage {
 
     /// The element is recognized but not represented in the output.
     ///
-    /// The element conveys meaningful information, but this information has not been parsed and is not represented in the output. This applies for example to the templates `Doplnit` and `Viz` and the extension tag `ref`. In contrast to other warnings, this warning does not indicate there is anything wrong with the wiki text. It just indicates that the wiki text contains additional information that is not represented in the output. The element is recognized as valid in the position it occurs, but its content is not parsed, and nothing can be said about whether the content is valid.
+    /// The element conveys meaningful information, but this information has not been parsed and is not represented in the output. This applies for example to the template `Doplnit`. In contrast to other warnings, this warning does not indicate there is anything wrong with the wiki text. It just indicates that the wiki text contains additional information that is not represented in the output. The element is recognized as valid in the position it occurs, but its content is not parsed, and nothing can be said about whether the content is valid.
     Supplementary,
 
+    /// The value of the element doesn't match the title of the page.
+    ///
+    /// This applies for example to a hyphenation that doesn't contain the same letters as the title, or an inflection where none of the basic forms is the title.
+    TitleMismatch,
+
     /// The element is not recognized.
     ///
     /// This may be because of the type of the element itself or because of anything inside it.
@@ -562,30 +1066,114 @@ pub enum WarningMessage {
     ValueUnrecognized,
 }
 
+impl WarningMessage {
+    /// Returns how serious warnings of this kind are.
+    #[must_use]
+    pub fn severity(self) -> Severity {
+        match self {
+            WarningMessage::Duplicate
+            | WarningMessage::Unrecognized
+            | WarningMessage::ValueConflicting
+            | WarningMessage::ValueUnrecognized => Severity::Error,
+            WarningMessage::Empty | WarningMessage::SectionEmpty | WarningMessage::TitleMismatch => {
+                Severity::Warning
+            }
+            WarningMessage::Supplementary => Severity::Info,
+        }
+    }
+}
+
 /// Parses an article from the Czech language version of Wiktionary into structured data.
 ///
 /// `wiki_text` is the wiki text of the article. `nodes` is the sequence of nodes obtained by parsing the wiki text with the crate [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text).
 #[must_use]
 pub fn parse<'a>(wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
+    parse_with_title(None, wiki_text, nodes, LanguageRegistry::new())
+}
+
+/// Parses an article from the Czech language version of Wiktionary into structured data, checking the content against the title of the page.
+///
+/// `title` is the title of the page and is stored in the output. Otherwise this is the same as [`parse`](fn.parse.html), except that elements that should match the title but don't produce warnings with the message [`TitleMismatch`](enum.WarningMessage.html#variant.TitleMismatch).
+#[must_use]
+pub fn parse_page<'a>(title: &'a str, wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
+    parse_with_title(Some(title), wiki_text, nodes, LanguageRegistry::new())
+}
+
+/// Parses an article from the Czech language version of Wiktionary into structured data, with options.
+///
+/// `title` is the title of the page if known, as for [`parse_page`](fn.parse_page.html). Unless strict mode is enabled in `options`, this always returns the output. In strict mode, if any warning of a kind considered fatal occurs, the fatal warnings are returned instead of the output.
+pub fn parse_with_options<'a>(
+    title: Option<&'a str>,
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    options: &ParseOptions,
+) -> Result<Output<'a>, Vec<Warning>> {
+    let mut output = parse_with_title(title, wiki_text, nodes, options.languages.clone());
+    output
+        .warnings
+        .retain(|warning| !options.suppressed_warnings.contains(&warning.code));
+    if options.strict
+        && output
+            .warnings
+            .iter()
+            .any(|warning| options.fatal_warnings.contains(&warning.message))
+    {
+        output
+            .warnings
+            .retain(|warning| options.fatal_warnings.contains(&warning.message));
+        return Err(output.warnings);
+    }
+    Ok(output)
+}
+
+fn parse_with_title<'a>(
+    title: Option<&'a str>,
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    languages: LanguageRegistry,
+) -> Output<'a> {
     let mut context = Context {
+        form: false,
         language: None,
+        language_sections: vec![],
+        languages,
+        pos: None,
+        reference_indices: HashMap::new(),
+        references: vec![],
+        title,
         warnings: vec![],
         wiki_text,
     };
     let mut external_links = None;
     let mut language_entries = vec![];
     let mut node_index = 0;
+    let mut notes = vec![];
+    notes::collect_references(&mut context, nodes);
+    let reference_warnings = std::mem::take(&mut context.warnings);
     let node_limit = nodes.len()
         - nodes
             .iter()
             .rev()
             .take_while(|node| match node {
                 Node::Category { .. } | Node::ParagraphBreak { .. } => true,
-                ::Node::Text { value, .. } => value.trim_left().is_empty(),
+                ::Node::Text { value, .. } => value.trim_start().is_empty(),
                 _ => false,
             })
             .count();
     let nodes = &nodes[0..node_limit];
+    let mut redirect = None;
+    let mut see_also = vec![];
+    if let Some(Node::Redirect { target, .. }) = nodes.first() {
+        node_index += 1;
+        let mut parts = target.splitn(2, '#');
+        redirect = Some(Redirect {
+            target: Cow::Borrowed(parts.next().unwrap()),
+            anchor: parts
+                .next()
+                .filter(|anchor| !anchor.is_empty())
+                .map(Cow::Borrowed),
+        });
+    }
     while let Some(node) = nodes.get(node_index) {
         node_index += 1;
         match node {
@@ -596,7 +1184,7 @@ pub fn parse<'a>(wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
                 ..
             } => if *level < 3 {
                 if *level < 2 {
-                    add_warning(&mut context, node, WarningMessage::Unrecognized);
+                    add_warning(&mut context, node, WarningCode::UnrecognizedHeading);
                     break;
                 }
                 if let Some(title) = parse_text(title) {
@@ -606,7 +1194,7 @@ pub fn parse<'a>(wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
                             node,
                             &nodes[node_index..],
                             &mut language_entries,
-                            language,
+                            EntryLanguage::Known(language),
                         );
                         continue;
                     }
@@ -620,47 +1208,60 @@ pub fn parse<'a>(wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
                             );
                             continue;
                         }
-                        "poznámky" => while let Some(node) = nodes.get(node_index) {
-                            match node {
-                                Node::Heading { .. } => break,
-                                Node::UnorderedList { .. } => {
-                                    add_warning(&mut context, node, WarningMessage::Supplementary)
-                                }
-                                Node::Tag { name, nodes, .. } => if name != "references" {
-                                    add_warning(&mut context, node, WarningMessage::Unrecognized);
-                                } else if !nodes.is_empty() {
-                                    add_warning(
-                                        &mut context,
-                                        node,
-                                        WarningMessage::ValueUnrecognized,
-                                    );
-                                },
-                                _ => unrecognized_unless_ignored(&mut context, node),
+                        "poznámky" => {
+                            node_index +=
+                                notes::parse_notes(&mut context, &nodes[node_index..], &mut notes);
+                            continue;
+                        }
+                        "" => {}
+                        _ => {
+                            let code = context
+                                .languages
+                                .language_code(&title)
+                                .map(|code| Cow::Owned(code.to_string()));
+                            if code.is_none() {
+                                add_warning(&mut context, node, WarningCode::UnknownLanguage);
                             }
-                            node_index += 1;
-                        },
-                        _ => {}
+                            node_index += language::parse_language(
+                                &mut context,
+                                node,
+                                &nodes[node_index..],
+                                &mut language_entries,
+                                EntryLanguage::Other { code, name: title },
+                            );
+                            continue;
+                        }
                     }
                 }
             },
-            Node::Template { name, .. } => add_warning(
-                &mut context,
-                node,
-                if text_equals(name, "Viz") {
-                    WarningMessage::Supplementary
-                } else {
-                    WarningMessage::Unrecognized
-                },
-            ),
-            ::Node::Text { value, .. } => if !value.trim_left().is_empty() {
-                add_warning(&mut context, node, WarningMessage::Unrecognized);
+            Node::Template {
+                name, parameters, ..
+            } => if text_equals(name, "Viz") {
+                // The parameters have the same format as labels: a list of distinct texts.
+                see_also.extend(templates::parse_template_labels(
+                    &mut context,
+                    node,
+                    parameters,
+                ));
+            } else {
+                add_warning(&mut context, node, WarningCode::UnrecognizedTemplate);
+            },
+            ::Node::Text { value, .. } => if !value.trim_start().is_empty() {
+                add_warning(&mut context, node, WarningCode::UnrecognizedElement);
             },
-            _ => add_warning(&mut context, node, WarningMessage::Unrecognized),
+            _ => add_warning(&mut context, node, WarningCode::UnrecognizedElement),
         }
     }
+    notes::merge_reference_warnings(&mut context, reference_warnings);
+    location::locate_warnings(nodes, &mut context.warnings);
     Output {
         external_links: external_links.unwrap_or_default().unwrap_or_default(),
         language_entries,
+        notes,
+        redirect,
+        references: context.references,
+        see_also,
+        title: title.map(Cow::Borrowed),
         warnings: context.warnings,
     }
 }
@@ -0,0 +1,87 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use parse_wiki_text::Positioned;
+
+pub fn locate_warnings(nodes: &[::Node], warnings: &mut [::Warning]) {
+    for warning in warnings {
+        warning.section = nodes
+            .iter()
+            .take_while(|node| node.start() <= warning.start)
+            .filter_map(|node| match node {
+                ::Node::Heading { nodes, .. } => Some(nodes),
+                _ => None,
+            })
+            .last()
+            .and_then(|nodes| ::parse_text(nodes))
+            .map(|text| text.trim().to_string());
+        let mut template = None;
+        let mut parameter = None;
+        find_template(nodes, warning, &mut template, &mut parameter);
+        warning.template = template;
+        warning.parameter = parameter;
+    }
+}
+
+fn find_template(
+    nodes: &[::Node],
+    warning: &::Warning,
+    template: &mut Option<String>,
+    parameter: &mut Option<String>,
+) {
+    let node = match nodes
+        .iter()
+        .find(|node| node.start() <= warning.start && warning.end <= node.end())
+    {
+        None => return,
+        Some(node) => node,
+    };
+    match node {
+        ::Node::DefinitionList { items, .. } => {
+            if let Some(item) = find_item(items, warning) {
+                find_template(&item.nodes, warning, template, parameter);
+            }
+        }
+        ::Node::ExternalLink { nodes, .. }
+        | ::Node::Heading { nodes, .. }
+        | ::Node::Preformatted { nodes, .. }
+        | ::Node::Tag { nodes, .. } => find_template(nodes, warning, template, parameter),
+        ::Node::Image { text, .. } | ::Node::Link { text, .. } => {
+            find_template(text, warning, template, parameter)
+        }
+        ::Node::OrderedList { items, .. } | ::Node::UnorderedList { items, .. } => {
+            if let Some(item) = find_item(items, warning) {
+                find_template(&item.nodes, warning, template, parameter);
+            }
+        }
+        ::Node::Template {
+            name, parameters, ..
+        } => {
+            *template = ::parse_text(name).map(|name| name.trim().to_string());
+            *parameter = None;
+            let mut position = 0;
+            for template_parameter in parameters {
+                if template_parameter.name.is_none() {
+                    position += 1;
+                }
+                if template_parameter.start <= warning.start && warning.end <= template_parameter.end
+                {
+                    *parameter = match &template_parameter.name {
+                        None => Some(position.to_string()),
+                        Some(name) => ::parse_text(name).map(|name| name.trim().to_string()),
+                    };
+                    find_template(&template_parameter.value, warning, template, parameter);
+                    break;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn find_item<'a, T: Positioned>(items: &'a [T], warning: &::Warning) -> Option<&'a T> {
+    items
+        .iter()
+        .find(|item| item.start() <= warning.start && warning.end <= item.end())
+}
@@ -0,0 +1,357 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+extern crate parse_wiktionary_cs;
+extern crate serde;
+extern crate serde_json;
+
+use parse_wiktionary_cs::{
+    dump, report::Report, Locale, Output, ParseOptions, RenderFormat, RenderOptions, WarningCode,
+};
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+};
+
+const USAGE: &str = "usage: parse_wiktionary_cs [options] <command> <file>
+
+Commands:
+    parse <file>       Parse a page and print the output as JSON.
+    report <file>      Print the number of warnings by kind, language, section and template in a page or dump, with example pages.
+    dump <file>        Parse each page in the main namespace of a dump and print one line of JSON per page.
+    warnings <file>    Print the warnings for a page or each page in a dump, with the surrounding wiki text.
+    stats <file>       Print the number of entries for each language and part of speech in a page or dump.
+
+A file name ending with .xml or .bz2 is read as a dump. The file name - reads a page from standard input.
+
+Options:
+    --compact     Print JSON without whitespace. This is the default for the command dump.
+    --explain L   Print an explanation and a suggested fix for each warning in the language L, cs or en.
+    --markdown    Print the report as Markdown instead of JSON.
+    --pretty      Print JSON with indentation. This is the default for the command parse.
+    --suppress C  Leave out warnings with the code C, for example CS0101 or duplicate-language-section. Can be given more than once.
+    --no-color    Don't use ANSI escape codes in the output.
+    --threads N   Use N threads for parsing dumps. The default is the number of available CPUs.
+    --title T     Check a single page against the title T, as pages from a dump are checked against their titles.";
+
+// Pages from a dump are parsed in chunks of this size, so that the output of each chunk can be printed together with the titles of the pages before the next chunk is read.
+const CHUNK_SIZE: usize = 1000;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Command {
+    Dump,
+    Parse,
+    Report,
+    Stats,
+    Warnings,
+}
+
+struct Options {
+    color: bool,
+    explanation: Option<Locale>,
+    markdown: bool,
+    parse_options: ParseOptions,
+    pretty: Option<bool>,
+    threads: usize,
+    title: Option<String>,
+}
+
+#[derive(Default)]
+struct Statistics {
+    language_entries: HashMap<String, usize>,
+    pages: usize,
+    pos_entries: HashMap<String, usize>,
+    redirects: usize,
+    warnings: usize,
+}
+
+fn main() {
+    let mut command = None;
+    let mut options = Options {
+        color: true,
+        explanation: None,
+        markdown: false,
+        parse_options: ParseOptions::default(),
+        pretty: None,
+        threads: 0,
+        title: None,
+    };
+    let mut path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &arg as _ {
+            "--compact" => options.pretty = Some(false),
+            "--explain" => match args.next().as_ref().map(|locale| locale as _) {
+                Some("cs") => options.explanation = Some(Locale::Cs),
+                Some("en") => options.explanation = Some(Locale::En),
+                _ => invalid_use(),
+            },
+            "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            "--markdown" => options.markdown = true,
+            "--no-color" => options.color = false,
+            "--pretty" => options.pretty = Some(true),
+            "--suppress" => match args.next().and_then(|code| WarningCode::from_code(&code)) {
+                None => invalid_use(),
+                Some(code) => {
+                    options.parse_options.suppressed_warnings.insert(code);
+                }
+            },
+            "--threads" => match args.next().and_then(|threads| threads.parse().ok()) {
+                None => invalid_use(),
+                Some(threads) => options.threads = threads,
+            },
+            "--title" => match args.next() {
+                None => invalid_use(),
+                Some(title) => options.title = Some(title),
+            },
+            _ => if command.is_none() {
+                command = Some(match &arg as _ {
+                    "dump" => Command::Dump,
+                    "parse" => Command::Parse,
+                    "report" => Command::Report,
+                    "stats" => Command::Stats,
+                    "warnings" => Command::Warnings,
+                    _ => invalid_use(),
+                });
+            } else if path.is_none() {
+                path = Some(arg);
+            } else {
+                invalid_use();
+            },
+        }
+    }
+    let (command, path) = match (command, path) {
+        (Some(command), Some(path)) => (command, path),
+        _ => invalid_use(),
+    };
+    let is_dump = path.ends_with(".xml") || path.ends_with(".bz2");
+    let configuration = parse_wiktionary_cs::create_configuration();
+    let stdout = std::io::stdout();
+    let mut stdout = stdout.lock();
+    let mut report = Report::default();
+    let mut statistics = Statistics::default();
+    if is_dump {
+        if command == Command::Parse {
+            fail("The command parse requires a single page, not a dump.");
+        }
+        let mut pages = match dump::open(&path) {
+            Err(error) => fail(&format!("Failed to open dump: {}", error)),
+            Ok(pages) => pages.filter_map(|page| match page {
+                Err(error) => fail(&format!("Failed to read dump: {}", error)),
+                Ok(page) => if page.namespace == 0 {
+                    Some(page)
+                } else {
+                    None
+                },
+            }),
+        };
+        loop {
+            let chunk = pages.by_ref().take(CHUNK_SIZE).collect::<Vec<_>>();
+            if chunk.is_empty() {
+                break;
+            }
+            let mut chunk_pages = chunk.iter();
+            parse_wiktionary_cs::batch::parse(
+                &configuration,
+                &options.parse_options,
+                options.threads,
+                chunk
+                    .iter()
+                    .map(|page| (page.title.as_str(), page.text.as_str())),
+                |result| {
+                    // Strict mode is never enabled, so parsing can't fail.
+                    let output = result.unwrap();
+                    let page = chunk_pages.next().unwrap();
+                    match command {
+                        Command::Dump => {
+                            let line = serde_json::json!({
+                                "title": page.title,
+                                "revision_id": page.revision_id,
+                                "output": output,
+                            });
+                            print_json(&mut stdout, &options, false, &line);
+                        }
+                        Command::Parse => unreachable!(),
+                        Command::Report => report.add(&page.title, &output),
+                        Command::Stats => statistics.add(&output),
+                        Command::Warnings => if !output.warnings.is_empty() {
+                            write(&mut stdout, format_args!("\n=== {} ===\n", page.title));
+                            print_warnings(&mut stdout, &options, &page.text, &output);
+                        },
+                    }
+                },
+            );
+        }
+    } else {
+        if command == Command::Dump {
+            fail("The command dump requires a dump, not a single page.");
+        }
+        let wiki_text = if path == "-" {
+            let mut wiki_text = String::new();
+            if let Err(error) = std::io::stdin().read_to_string(&mut wiki_text) {
+                fail(&format!("Failed to read standard input: {}", error));
+            }
+            wiki_text
+        } else {
+            match std::fs::read_to_string(&path) {
+                Err(error) => fail(&format!("Failed to read file: {}", error)),
+                Ok(file_contents) => file_contents,
+            }
+        };
+        let result = configuration.parse(&wiki_text);
+        for warning in &result.warnings {
+            eprint!(
+                "\n{}",
+                parse_wiktionary_cs::render_wiki_text_warning(
+                    warning,
+                    &wiki_text,
+                    &render_options(&options)
+                )
+            );
+        }
+        // Strict mode is never enabled, so parsing can't fail.
+        let output = parse_wiktionary_cs::parse_with_options(
+            options.title.as_deref(),
+            &wiki_text,
+            &result.nodes,
+            &options.parse_options,
+        )
+        .unwrap();
+        match command {
+            Command::Dump => unreachable!(),
+            Command::Parse => print_json(&mut stdout, &options, true, &output),
+            Command::Report => report.add(&path, &output),
+            Command::Stats => statistics.add(&output),
+            Command::Warnings => print_warnings(&mut stdout, &options, &wiki_text, &output),
+        }
+    }
+    match command {
+        Command::Report => if options.markdown {
+            write(&mut stdout, format_args!("{}", report.to_markdown()));
+        } else {
+            print_json(&mut stdout, &options, true, &report);
+        },
+        Command::Stats => statistics.print(&mut stdout),
+        _ => {}
+    }
+}
+
+impl Statistics {
+    fn add(&mut self, output: &Output) {
+        self.pages += 1;
+        if output.redirect.is_some() {
+            self.redirects += 1;
+        }
+        self.warnings += output.warnings.len();
+        for language_entry in &output.language_entries {
+            *self
+                .language_entries
+                .entry(
+                    language_entry
+                        .language
+                        .language_code()
+                        .unwrap_or_else(|| language_entry.language.name())
+                        .to_string(),
+                )
+                .or_insert(0) += 1;
+            for pos_entry in &language_entry.pos_entries {
+                *self
+                    .pos_entries
+                    .entry(format!("{:?}", pos_entry.pos))
+                    .or_insert(0) += 1;
+            }
+        }
+    }
+
+    fn print(&self, stdout: &mut impl Write) {
+        write(stdout, format_args!("pages\t{}\n", self.pages));
+        write(stdout, format_args!("redirects\t{}\n", self.redirects));
+        write(
+            stdout,
+            format_args!(
+                "language entries\t{}\n",
+                self.language_entries.values().sum::<usize>()
+            ),
+        );
+        write(
+            stdout,
+            format_args!(
+                "pos entries\t{}\n",
+                self.pos_entries.values().sum::<usize>()
+            ),
+        );
+        write(stdout, format_args!("warnings\t{}\n", self.warnings));
+        for (heading, counts) in &[
+            ("language", &self.language_entries),
+            ("pos", &self.pos_entries),
+        ] {
+            write(stdout, format_args!("\n{}\tentries\n", heading));
+            let mut counts = counts.iter().collect::<Vec<_>>();
+            counts.sort_by(|(name1, count1), (name2, count2)| {
+                count2.cmp(count1).then(name1.cmp(name2))
+            });
+            for (name, count) in counts {
+                write(stdout, format_args!("{}\t{}\n", name, count));
+            }
+        }
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
+
+fn invalid_use() -> ! {
+    eprintln!("{}", USAGE);
+    std::process::exit(1);
+}
+
+fn print_json(
+    stdout: &mut impl Write,
+    options: &Options,
+    pretty_default: bool,
+    value: &impl serde::Serialize,
+) {
+    let result = if options.pretty.unwrap_or(pretty_default) {
+        serde_json::to_writer_pretty(&mut *stdout, value)
+    } else {
+        serde_json::to_writer(&mut *stdout, value)
+    };
+    if let Err(error) = result {
+        fail(&format!("Failed to write output: {}", error));
+    }
+    write(stdout, format_args!("\n"));
+}
+
+fn print_warnings(stdout: &mut impl Write, options: &Options, wiki_text: &str, output: &Output) {
+    let render_options = render_options(options);
+    for warning in &output.warnings {
+        write(
+            stdout,
+            format_args!("\n{}", warning.render(wiki_text, &render_options)),
+        );
+    }
+}
+
+fn render_options(options: &Options) -> RenderOptions {
+    RenderOptions {
+        format: if options.color {
+            RenderFormat::Ansi
+        } else {
+            RenderFormat::Plain
+        },
+        explanation: options.explanation,
+        ..RenderOptions::default()
+    }
+}
+
+fn write(stdout: &mut impl Write, arguments: std::fmt::Arguments) {
+    if let Err(error) = stdout.write_fmt(arguments) {
+        fail(&format!("Failed to write output: {}", error));
+    }
+}
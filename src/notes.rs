@@ -0,0 +1,182 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use std::collections::HashMap;
+
+pub fn collect_references<'a>(context: &mut ::Context<'a>, nodes: &[::Node<'a>]) {
+    let mut names = HashMap::new();
+    let mut reused = vec![];
+    collect_references_recursive(context, nodes, &mut names, &mut reused);
+    // A reference without content may refer by name to a reference with content that comes after it.
+    for (start, name) in reused {
+        if let Some(index) = names.get(name) {
+            context.reference_indices.insert(start, *index);
+        }
+    }
+}
+
+// Warnings from the content of references are collected before the language sections are parsed. They are given the language of the section they occur in and put in source order among the other warnings.
+pub fn merge_reference_warnings(context: &mut ::Context, reference_warnings: Vec<::Warning>) {
+    let language_sections = &context.language_sections;
+    let mut reference_warnings = reference_warnings
+        .into_iter()
+        .map(|mut warning| {
+            warning.language = language_sections
+                .iter()
+                .find(|(start, end, _)| *start <= warning.start && warning.start < *end)
+                .map(|(_, _, language)| ::owned::IntoStatic::into_static(language.clone()));
+            warning
+        })
+        .peekable();
+    let mut warnings = vec![];
+    for warning in context.warnings.drain(..) {
+        while let Some(reference_warning) =
+            reference_warnings.next_if(|reference_warning| reference_warning.start < warning.start)
+        {
+            warnings.push(reference_warning);
+        }
+        warnings.push(warning);
+    }
+    warnings.extend(reference_warnings);
+    context.warnings = warnings;
+}
+
+pub fn parse_notes<'a>(
+    context: &mut ::Context<'a>,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<Vec<::Flowing<'a>>>,
+) -> usize {
+    let mut node_index = 0;
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Heading { .. } => break,
+            ::Node::UnorderedList { items, .. } => for item in items {
+                if item.nodes.is_empty() {
+                    ::add_warning(context, item, ::WarningCode::EmptyListItem);
+                } else {
+                    output.push(parse_flowing(context, &item.nodes));
+                }
+            },
+            ::Node::Tag { name, nodes, .. } => if name != "references" {
+                ::add_warning(context, node, ::WarningCode::UnrecognizedElement);
+            } else if !nodes.is_empty() {
+                ::add_warning(context, node, ::WarningCode::InvalidReferences);
+            },
+            _ => ::unrecognized_unless_ignored(context, node),
+        }
+        node_index += 1;
+    }
+    node_index
+}
+
+#[allow(clippy::collapsible_match)]
+fn collect_references_recursive<'a>(
+    context: &mut ::Context<'a>,
+    nodes: &[::Node<'a>],
+    names: &mut HashMap<&'a str, usize>,
+    reused: &mut Vec<(usize, &'a str)>,
+) {
+    for node in nodes {
+        match node {
+            ::Node::DefinitionList { items, .. } => for item in items {
+                collect_references_recursive(context, &item.nodes, names, reused);
+            },
+            ::Node::ExternalLink { nodes, .. }
+            | ::Node::Heading { nodes, .. }
+            | ::Node::Preformatted { nodes, .. } => {
+                collect_references_recursive(context, nodes, names, reused)
+            }
+            ::Node::Image { text, .. } | ::Node::Link { text, .. } => {
+                collect_references_recursive(context, text, names, reused)
+            }
+            ::Node::OrderedList { items, .. } | ::Node::UnorderedList { items, .. } => {
+                for item in items {
+                    collect_references_recursive(context, &item.nodes, names, reused);
+                }
+            }
+            ::Node::Tag {
+                name: tag_name,
+                nodes,
+                start,
+                end,
+            } => if tag_name == "ref" {
+                let wiki_text = context.wiki_text;
+                let start_tag = &wiki_text[*start..*end];
+                let name = parse_name(&start_tag[..start_tag.find('>').unwrap_or(start_tag.len())]);
+                if nodes.is_empty() {
+                    if let Some(name) = name {
+                        reused.push((*start, name));
+                    }
+                    continue;
+                }
+                if let Some(index) = name.and_then(|name| names.get(name)) {
+                    context.reference_indices.insert(*start, *index);
+                    continue;
+                }
+                let index = context.references.len();
+                let reference = parse_flowing(context, nodes);
+                context.references.push(reference);
+                context.reference_indices.insert(*start, index);
+                if let Some(name) = name {
+                    names.insert(name, index);
+                }
+            },
+            ::Node::Template { parameters, .. } => for parameter in parameters {
+                collect_references_recursive(context, &parameter.value, names, reused);
+            },
+            _ => {}
+        }
+    }
+}
+
+fn parse_flowing<'a>(context: &mut ::Context<'a>, nodes: &[::Node<'a>]) -> Vec<::Flowing<'a>> {
+    nodes
+        .iter()
+        .map(|node| match node {
+            ::Node::Italic { .. } => ::Flowing::Italic,
+            ::Node::Link { target, text, .. } => ::parse_link(context, node, target, text),
+            ::Node::Text { value, .. } => ::Flowing::Text {
+                value: ::Cow::Borrowed(value),
+            },
+            _ => ::create_unknown(context, node),
+        })
+        .collect()
+}
+
+fn parse_name(start_tag: &str) -> Option<&str> {
+    let is_separator = |character: char| character.is_whitespace() || character == '/';
+    let mut attributes = start_tag
+        .trim_start_matches('<')
+        .trim_start_matches(char::is_alphanumeric);
+    loop {
+        attributes = attributes.trim_start_matches(is_separator);
+        let name_end = attributes
+            .find(|character: char| is_separator(character) || character == '=')
+            .unwrap_or(attributes.len());
+        if name_end == 0 {
+            return None;
+        }
+        let name = &attributes[..name_end];
+        attributes = attributes[name_end..].trim_start();
+        let mut value = "";
+        if attributes.starts_with('=') {
+            attributes = attributes[1..].trim_start();
+            let (value_start, value_end, next_start) = match attributes.chars().next() {
+                Some(quote @ '"') | Some(quote @ '\'') => match attributes[1..].find(quote) {
+                    None => (1, attributes.len(), attributes.len()),
+                    Some(position) => (1, position + 1, position + 2),
+                },
+                _ => {
+                    let end = attributes.find(is_separator).unwrap_or(attributes.len());
+                    (0, end, end)
+                }
+            };
+            value = &attributes[value_start..value_end];
+            attributes = &attributes[next_start..];
+        }
+        if name.eq_ignore_ascii_case("name") {
+            return if value.is_empty() { None } else { Some(value) };
+        }
+    }
+}
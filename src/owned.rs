@@ -0,0 +1,196 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use std::hash::Hash;
+
+pub trait IntoStatic {
+    type Static;
+
+    fn into_static(self) -> Self::Static;
+}
+
+macro_rules! into_static_copy {
+    ( $( $type:ty ),+ ) => { $(
+        impl IntoStatic for $type {
+            type Static = Self;
+
+            fn into_static(self) -> Self {
+                self
+            }
+        }
+    )+ }
+}
+
+macro_rules! into_owned_struct {
+    ( $( $type:ident $( $field:ident )+ ),+ ) => { $(
+        impl<'a> IntoStatic for ::$type<'a> {
+            type Static = ::$type<'static>;
+
+            fn into_static(self) -> Self::Static {
+                ::$type {
+                    $( $field: self.$field.into_static() ),+
+                }
+            }
+        }
+
+        impl<'a> ::$type<'a> {
+            /// Converts into a value that doesn't borrow from the wiki text, by copying all borrowed strings.
+            #[must_use]
+            pub fn into_owned(self) -> ::$type<'static> {
+                self.into_static()
+            }
+        }
+    )+ }
+}
+
+into_static_copy! { bool, usize, ::Animacy, ::Aspect, ::ExternalLinkType, ::Gender, ::GrammaticalTag, ::Language, ::Pos, ::Transitivity, ::Warning }
+
+into_owned_struct! {
+    Audio file_name label,
+    Definition children definition examples labels phrase references,
+    Example example references,
+    ExternalLink kind type_ value,
+    FormOf lemma tags,
+    InflectionEntry details inflection,
+    LanguageEntry audio etymology homophones hyphenation ipa language pos_entries variants,
+    Output external_links language_entries notes redirect references see_also title warnings,
+    PosEntry animacy antonyms aspect aspect_counterparts compound_words definitions details etymology form form_of gender homonym_index inflection phrases_and_idioms pos proverbs related_terms synonyms transitivity translations variants,
+    Redirect anchor target,
+    Translations gloss other_translations translations
+}
+
+impl<'a> IntoStatic for ::Cow<'a, str> {
+    type Static = ::Cow<'static, str>;
+
+    fn into_static(self) -> Self::Static {
+        ::Cow::Owned(self.into_owned())
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for Option<T> {
+    type Static = Option<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.map(IntoStatic::into_static)
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for Vec<T> {
+    type Static = Vec<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(IntoStatic::into_static).collect()
+    }
+}
+
+impl<K: IntoStatic, T: IntoStatic> IntoStatic for ::HashMap<K, T>
+where
+    K::Static: Eq + Hash,
+{
+    type Static = ::HashMap<K::Static, T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_iter()
+            .map(|(key, value)| (key.into_static(), value.into_static()))
+            .collect()
+    }
+}
+
+impl<'a> IntoStatic for ::EntryLanguage<'a> {
+    type Static = ::EntryLanguage<'static>;
+
+    fn into_static(self) -> Self::Static {
+        match self {
+            ::EntryLanguage::Known(language) => ::EntryLanguage::Known(language),
+            ::EntryLanguage::Other { code, name } => ::EntryLanguage::Other {
+                code: code.into_static(),
+                name: name.into_static(),
+            },
+        }
+    }
+}
+
+impl<'a> IntoStatic for ::Flowing<'a> {
+    type Static = ::Flowing<'static>;
+
+    fn into_static(self) -> Self::Static {
+        match self {
+            ::Flowing::Italic => ::Flowing::Italic,
+            ::Flowing::Labels { labels } => ::Flowing::Labels {
+                labels: labels.into_static(),
+            },
+            ::Flowing::Link { target, text } => ::Flowing::Link {
+                target: target.into_static(),
+                text: text.into_static(),
+            },
+            ::Flowing::Plural => ::Flowing::Plural,
+            ::Flowing::Qualifier { value } => ::Flowing::Qualifier {
+                value: value.into_static(),
+            },
+            ::Flowing::Text { value } => ::Flowing::Text {
+                value: value.into_static(),
+            },
+            ::Flowing::Translation { gender, term } => ::Flowing::Translation {
+                gender: gender.into_static(),
+                term: term.into_static(),
+            },
+            ::Flowing::Unknown { value } => ::Flowing::Unknown {
+                value: value.into_static(),
+            },
+        }
+    }
+}
+
+impl<'a> ::Flowing<'a> {
+    /// Converts into a value that doesn't borrow from the wiki text, by copying all borrowed strings.
+    #[must_use]
+    pub fn into_owned(self) -> ::Flowing<'static> {
+        self.into_static()
+    }
+}
+
+impl<'a> IntoStatic for ::Inflection<'a> {
+    type Static = ::Inflection<'static>;
+
+    fn into_static(self) -> Self::Static {
+        match self {
+            ::Inflection::AdjectiveDeclensionBasic(inflection) => {
+                ::Inflection::AdjectiveDeclensionBasic(inflection.into_static())
+            }
+            ::Inflection::Comparison(inflection) => {
+                ::Inflection::Comparison(inflection.into_static())
+            }
+            ::Inflection::Conjugation(inflection) => {
+                ::Inflection::Conjugation(inflection.into_static())
+            }
+            ::Inflection::Indeclinable => ::Inflection::Indeclinable,
+            ::Inflection::NounDeclensionBasic(inflection) => {
+                ::Inflection::NounDeclensionBasic(inflection.into_static())
+            }
+            ::Inflection::NumeralDeclensionAdjective(inflection) => {
+                ::Inflection::NumeralDeclensionAdjective(inflection.into_static())
+            }
+            ::Inflection::NumeralDeclensionBasic(inflection) => {
+                ::Inflection::NumeralDeclensionBasic(inflection.into_static())
+            }
+            ::Inflection::NumeralDeclensionSgPl(inflection) => {
+                ::Inflection::NumeralDeclensionSgPl(inflection.into_static())
+            }
+            ::Inflection::PronounDeclensionAdjective(inflection) => {
+                ::Inflection::PronounDeclensionAdjective(inflection.into_static())
+            }
+            ::Inflection::PronounDeclensionBasic(inflection) => {
+                ::Inflection::PronounDeclensionBasic(inflection.into_static())
+            }
+        }
+    }
+}
+
+impl<'a> ::Inflection<'a> {
+    /// Converts into a value that doesn't borrow from the wiki text, by copying all borrowed strings.
+    #[must_use]
+    pub fn into_owned(self) -> ::Inflection<'static> {
+        self.into_static()
+    }
+}
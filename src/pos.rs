@@ -8,12 +8,15 @@ pub fn parse_pos<'a>(
     nodes: &[::Node<'a>],
     pos_entries: &mut Vec<::PosEntry<'a>>,
     pos: ::Pos,
+    homonym_index: Option<usize>,
+    form: bool,
 ) -> usize {
     let mut antonyms = None;
     let mut compound_words = None;
     let mut definitions = None;
     let mut details = None;
     let mut etymology = None;
+    let mut grammar = ::details::Grammar::default();
     let mut inflection = vec![];
     let mut node_index = 0;
     let mut phrases_and_idioms = None;
@@ -22,6 +25,8 @@ pub fn parse_pos<'a>(
     let mut synonyms = None;
     let mut translations = None;
     let mut variants = None;
+    context.form = form;
+    context.pos = Some(pos);
     while let Some(node) = nodes.get(node_index) {
         macro_rules! parse_section { ( $function:path, $( $output:tt )+ ) => {
             $function(context, node, &nodes[node_index..], &mut $( $output )+ )
@@ -78,39 +83,63 @@ pub fn parse_pos<'a>(
                                 parse_section!(::inflection::parse_conjugation, inflection, pos)
                             }
                             _ => {
-                                ::add_warning(context, node, ::WarningMessage::Unrecognized);
+                                ::add_warning(context, node, ::WarningCode::UnrecognizedHeading);
                                 0
                             }
                         }
                     }
-                    _ => ::add_warning(context, node, ::WarningMessage::Unrecognized),
+                    _ => ::add_warning(context, node, ::WarningCode::UnrecognizedHeading),
                 }
             }
             ::Node::UnorderedList { items, .. } => {
                 node_index += 1;
                 ::details::parse_details(context, node, items, &mut details);
+                ::details::parse_grammar(context, items, &mut grammar);
             }
             _ => {
                 node_index += 1;
-                ::add_warning(context, node, ::WarningMessage::Unrecognized);
+                ::add_warning(context, node, ::WarningCode::UnrecognizedElement);
             }
         }
     }
     if definitions.is_none() {
-        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+        ::add_warning(context, heading_node, ::WarningCode::MissingDefinitions);
     }
+    context.form = false;
+    context.pos = None;
+    let definitions = definitions.unwrap_or_default().unwrap_or_default();
+    let form_of = if form {
+        ::form_of::parse_form_of(&definitions)
+    } else {
+        vec![]
+    };
     pos_entries.push(::PosEntry {
+        animacy: grammar.animacy,
         antonyms: antonyms.unwrap_or_default().unwrap_or_default(),
+        aspect: grammar.aspect.or_else(|| {
+            inflection
+                .iter()
+                .find_map(|entry| match &entry.inflection {
+                    Some(::Inflection::Conjugation(conjugation)) => conjugation.aspect,
+                    _ => None,
+                })
+        }),
+        aspect_counterparts: grammar.aspect_counterparts,
         compound_words: compound_words.unwrap_or_default().unwrap_or_default(),
-        definitions: definitions.unwrap_or_default().unwrap_or_default(),
+        definitions,
         details: details.unwrap_or_default(),
         etymology: etymology.unwrap_or_default().unwrap_or_default(),
+        form,
+        form_of,
+        gender: grammar.gender,
+        homonym_index,
         inflection,
         phrases_and_idioms: phrases_and_idioms.unwrap_or_default().unwrap_or_default(),
         pos,
         proverbs: proverbs.unwrap_or_default().unwrap_or_default(),
         related_terms: related_terms.unwrap_or_default().unwrap_or_default(),
         synonyms: synonyms.unwrap_or_default().unwrap_or_default(),
+        transitivity: grammar.transitivity,
         translations: translations.unwrap_or_default().unwrap_or_default(),
         variants: variants.unwrap_or_default().unwrap_or_default(),
     });
@@ -23,7 +23,7 @@ pub fn parse_pronunciation<'a>(
                 ::Node::Heading { .. } => break,
                 ::Node::UnorderedList { items, .. } => {
                     list_output = Some(if list_output.is_some() {
-                        ::add_warning(context, node, ::WarningMessage::Duplicate);
+                        ::add_warning(context, node, ::WarningCode::DuplicateList);
                         None
                     } else {
                         parse_list(context, node, items)
@@ -34,7 +34,7 @@ pub fn parse_pronunciation<'a>(
             node_index += 1;
         }
         if list_output.is_none() {
-            ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+            ::add_warning(context, heading_node, ::WarningCode::EmptySection);
         }
         let mut homophones = None;
         while let Some(node) = nodes.get(node_index) {
@@ -70,6 +70,7 @@ pub fn parse_pronunciation<'a>(
     })
 }
 
+#[allow(clippy::collapsible_match)]
 fn parse_list<'a>(
     context: &mut ::Context<'a>,
     list_node: &::Node,
@@ -191,7 +192,7 @@ fn parse_list<'a>(
         },
         _ => {}
     }
-    ::add_warning(context, list_node, ::WarningMessage::ValueUnrecognized);
+    ::add_warning(context, list_node, ::WarningCode::InvalidPronunciation);
     None
 }
 
@@ -208,7 +209,7 @@ fn parse_template_audio<'a>(
             label: parse_parameter_text_not_empty(context, label_parameter)?,
         })
     } else {
-        ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+        ::add_warning(context, template_node, ::WarningCode::InvalidTemplateParameters);
         None
     }
 }
@@ -227,14 +228,14 @@ fn parse_template_ipa<'a>(
             let value1 = parse_parameter_text_not_empty(context, parameter1)?;
             let value2 = parse_parameter_text_not_empty(context, parameter2)?;
             if value1 == value2 {
-                ::add_warning(context, parameter2, ::WarningMessage::Duplicate);
+                ::add_warning(context, parameter2, ::WarningCode::DuplicateValue);
                 None
             } else {
                 Some(vec![value1, value2])
             }
         }
         _ => {
-            ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+            ::add_warning(context, template_node, ::WarningCode::InvalidTemplateParameters);
             None
         }
     }
@@ -246,7 +247,7 @@ fn parse_parameter_text_not_empty<'a>(
 ) -> Option<::Cow<'a, str>> {
     let result = ::parse_text_not_empty(&parameter.value);
     if result.is_none() {
-        ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+        ::add_warning(context, parameter, ::WarningCode::InvalidParameterValue);
     }
     result
 }
@@ -23,7 +23,7 @@ pub fn parse_proverbs<'a>(
                                 return Some(::Cow::Borrowed(*target));
                             }
                         }
-                        ::add_warning(context, item, ::WarningMessage::ValueUnrecognized);
+                        ::add_warning(context, item, ::WarningCode::InvalidRelatedTerm);
                         None
                     })
                     .collect(),
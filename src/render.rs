@@ -0,0 +1,320 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use std::fmt::Write;
+
+struct Diagnostic<'a> {
+    code: Option<&'static str>,
+    details: Vec<String>,
+    end: usize,
+    explanation: Option<(::Locale, ::Explanation)>,
+    message: &'a str,
+    severity: ::Severity,
+    start: usize,
+}
+
+struct Style {
+    bold: &'static str,
+    color: &'static str,
+    dim: &'static str,
+    mark: &'static str,
+    mark_end: &'static str,
+    reset: &'static str,
+}
+
+impl ::Warning {
+    /// Returns the line and column numbers, both starting from 1, of the start of the warning in the wiki text.
+    ///
+    /// The column is counted in characters, not bytes. `wiki_text` must be the wiki text that was parsed to produce the warning.
+    #[must_use]
+    pub fn line_column(&self, wiki_text: &str) -> (usize, usize) {
+        line_column(wiki_text, self.start)
+    }
+
+    /// Renders the warning as a diagnostic message with a snippet of the surrounding wiki text, in the style of a compiler error message.
+    ///
+    /// `wiki_text` must be the wiki text that was parsed to produce the warning.
+    #[must_use]
+    pub fn render(&self, wiki_text: &str, options: &::RenderOptions) -> String {
+        let mut details = vec![];
+        if let Some(language) = &self.language {
+            details.push(format!(
+                "language: {}",
+                language.language_code().unwrap_or_else(|| language.name())
+            ));
+        }
+        if let Some(pos) = self.pos {
+            details.push(format!("pos: {:?}", pos));
+        }
+        for (label, value) in &[
+            ("section", &self.section),
+            ("template", &self.template),
+            ("parameter", &self.parameter),
+        ] {
+            if let Some(value) = value {
+                details.push(format!("{}: {}", label, value));
+            }
+        }
+        render_diagnostic(
+            &Diagnostic {
+                code: Some(self.code.code()),
+                details,
+                end: self.end,
+                explanation: options
+                    .explanation
+                    .map(|locale| (locale, self.explanation(locale))),
+                message: self.code.name(),
+                severity: self.severity,
+                start: self.start,
+            },
+            wiki_text,
+            options,
+        )
+    }
+}
+
+/// Renders a warning from Parse Wiki Text about the markup of the wiki text in the same way as [`Warning::render`](struct.Warning.html#method.render).
+///
+/// These warnings have the severity [`Warning`](enum.Severity.html#variant.Warning) and no code or explanation. `wiki_text` must be the wiki text that was parsed to produce the warning.
+#[must_use]
+pub fn render_wiki_text_warning(
+    warning: &::parse_wiki_text::Warning,
+    wiki_text: &str,
+    options: &::RenderOptions,
+) -> String {
+    render_diagnostic(
+        &Diagnostic {
+            code: None,
+            details: vec![],
+            end: warning.end,
+            explanation: None,
+            message: warning.message.message(),
+            severity: ::Severity::Warning,
+            start: warning.start,
+        },
+        wiki_text,
+        options,
+    )
+}
+
+fn render_diagnostic(
+    diagnostic: &Diagnostic,
+    wiki_text: &str,
+    options: &::RenderOptions,
+) -> String {
+    let style = match options.format {
+        ::RenderFormat::Ansi => {
+            let color = match diagnostic.severity {
+                ::Severity::Error => "\x1b[91m",
+                ::Severity::Info => "\x1b[96m",
+                ::Severity::Warning => "\x1b[93m",
+            };
+            Style {
+                bold: "\x1b[1m",
+                color,
+                dim: "\x1b[94m",
+                mark: color,
+                mark_end: "\x1b[m",
+                reset: "\x1b[m",
+            }
+        }
+        ::RenderFormat::Html => Style {
+            bold: "",
+            color: "",
+            dim: "",
+            mark: "<mark>",
+            mark_end: "</mark>",
+            reset: "",
+        },
+        ::RenderFormat::Plain => Style {
+            bold: "",
+            color: "",
+            dim: "",
+            mark: "",
+            mark_end: "",
+            reset: "",
+        },
+    };
+    let escape = |text: &str| match options.format {
+        ::RenderFormat::Html => text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;"),
+        _ => text.to_string(),
+    };
+    let warning_start = floor_char_boundary(wiki_text, diagnostic.start);
+    let warning_end = ceil_char_boundary(wiki_text, diagnostic.end.max(warning_start));
+    let (line, column) = line_column(wiki_text, diagnostic.start);
+    let mut output = String::new();
+    if options.format == ::RenderFormat::Html {
+        output.push_str("<pre class=\"warning\">");
+    }
+    let mut lines_start = wiki_text[..warning_start]
+        .rfind('\n')
+        .map_or(0, |position| position + 1);
+    let mut first_line = line;
+    for _ in 0..options.context_lines {
+        if lines_start == 0 {
+            break;
+        }
+        lines_start = wiki_text[..lines_start - 1]
+            .rfind('\n')
+            .map_or(0, |position| position + 1);
+        first_line -= 1;
+    }
+    let mut lines_end = wiki_text[warning_end..]
+        .find('\n')
+        .map_or(wiki_text.len(), |position| warning_end + position);
+    for _ in 0..options.context_lines {
+        if lines_end == wiki_text.len() {
+            break;
+        }
+        lines_end = wiki_text[lines_end + 1..]
+            .find('\n')
+            .map_or(wiki_text.len(), |position| lines_end + 1 + position);
+    }
+    // Don't show the empty line after a final line break.
+    if lines_end == wiki_text.len() && lines_end > warning_end && wiki_text.ends_with('\n') {
+        lines_end -= 1;
+    }
+    let last_line = first_line + wiki_text[lines_start..lines_end].matches('\n').count();
+    let gutter_width = last_line.to_string().len();
+    let code = match diagnostic.code {
+        None => String::new(),
+        Some(code) => format!("[{}]", code),
+    };
+    writeln!(
+        output,
+        "{}{}{}{}{}: {}\n{}{:width$}-->{} {}:{}",
+        style.color,
+        style.bold,
+        severity_name(diagnostic.severity),
+        code,
+        style.reset,
+        escape(diagnostic.message),
+        style.dim,
+        "",
+        style.reset,
+        line,
+        column,
+        width = gutter_width
+    ).unwrap();
+    if !diagnostic.details.is_empty() {
+        writeln!(
+            output,
+            "{}{:width$} ={} {}",
+            style.dim,
+            "",
+            style.reset,
+            escape(&diagnostic.details.join(", ")),
+            width = gutter_width
+        ).unwrap();
+    }
+    writeln!(output, "{}{:width$} |{}", style.dim, "", style.reset, width = gutter_width).unwrap();
+    let mut line_start = lines_start;
+    for (line_index, line_text) in wiki_text[lines_start..lines_end].split('\n').enumerate() {
+        let line_end = line_start + line_text.len();
+        let highlight_start = warning_start.max(line_start).min(line_end);
+        let highlight_end = warning_end.min(line_end).max(highlight_start);
+        let is_highlighted = warning_start <= line_end && warning_end >= line_start;
+        write!(
+            output,
+            "{}{:>width$} |{} ",
+            style.dim,
+            first_line + line_index,
+            style.reset,
+            width = gutter_width
+        ).unwrap();
+        if is_highlighted && highlight_end > highlight_start {
+            write!(
+                output,
+                "{}{}{}{}{}",
+                escape(&wiki_text[line_start..highlight_start]),
+                style.mark,
+                escape(&wiki_text[highlight_start..highlight_end]),
+                style.mark_end,
+                escape(&wiki_text[highlight_end..line_end])
+            ).unwrap();
+        } else {
+            output.push_str(&escape(line_text));
+        }
+        output.push('\n');
+        if is_highlighted && options.format != ::RenderFormat::Html {
+            writeln!(
+                output,
+                "{}{:width$} |{} {:indent$}{}{}{}",
+                style.dim,
+                "",
+                style.reset,
+                "",
+                style.color,
+                "^".repeat(wiki_text[highlight_start..highlight_end].chars().count().max(1)),
+                style.reset,
+                width = gutter_width,
+                indent = wiki_text[line_start..highlight_start].chars().count()
+            ).unwrap();
+        }
+        line_start = line_end + 1;
+    }
+    if let Some((locale, explanation)) = diagnostic.explanation {
+        let (description_label, fix_label) = match locale {
+            ::Locale::Cs => ("popis", "oprava"),
+            ::Locale::En => ("note", "help"),
+        };
+        for (label, text) in &[
+            (description_label, explanation.description),
+            (fix_label, explanation.fix),
+        ] {
+            writeln!(
+                output,
+                "{}{:width$} ={} {}{}{}: {}",
+                style.dim,
+                "",
+                style.reset,
+                style.bold,
+                label,
+                style.reset,
+                escape(text),
+                width = gutter_width
+            ).unwrap();
+        }
+    }
+    if options.format == ::RenderFormat::Html {
+        output.push_str("</pre>\n");
+    }
+    output
+}
+
+fn ceil_char_boundary(text: &str, mut position: usize) -> usize {
+    position = position.min(text.len());
+    while !text.is_char_boundary(position) {
+        position += 1;
+    }
+    position
+}
+
+fn floor_char_boundary(text: &str, mut position: usize) -> usize {
+    position = position.min(text.len());
+    while !text.is_char_boundary(position) {
+        position -= 1;
+    }
+    position
+}
+
+fn line_column(wiki_text: &str, position: usize) -> (usize, usize) {
+    let position = floor_char_boundary(wiki_text, position);
+    let line_start = wiki_text[..position].rfind('\n').map_or(0, |line_end| line_end + 1);
+    (
+        wiki_text[..line_start].matches('\n').count() + 1,
+        wiki_text[line_start..position].chars().count() + 1,
+    )
+}
+
+fn severity_name(severity: ::Severity) -> &'static str {
+    match severity {
+        ::Severity::Error => "error",
+        ::Severity::Info => "info",
+        ::Severity::Warning => "warning",
+    }
+}
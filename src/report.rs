@@ -0,0 +1,184 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+//! Aggregating warnings across many pages, for finding the most common problems in a dump.
+//!
+//! A report counts the warnings by kind, code, language, section and template, and keeps the titles of a few pages as examples for each count. It can be exported as JSON and as Markdown.
+
+use std::collections::BTreeMap;
+
+/// The number of warnings with a particular value of a property.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Bucket {
+    /// The number of warnings.
+    pub count: usize,
+
+    /// The titles of the first pages where the warnings occurred, at most [`Report::examples`](struct.Report.html#structfield.examples) of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
+}
+
+/// Counts of warnings across many pages.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Report {
+    /// Warnings by code, identified by the code followed by the name of the code, for example `CS0101 duplicate-language-section`.
+    pub by_code: BTreeMap<String, Bucket>,
+
+    /// Warnings by the language of the language section in which they occurred, identified by language code, or by the heading of the section for languages without a language code.
+    ///
+    /// Warnings that occurred outside of any language section are not included.
+    pub by_language: BTreeMap<String, Bucket>,
+
+    /// Warnings by kind, identified by the name of the kind in snake case.
+    pub by_message: BTreeMap<String, Bucket>,
+
+    /// Warnings by the heading of the section in which they occurred.
+    ///
+    /// Warnings that occurred before the first heading are not included.
+    pub by_section: BTreeMap<String, Bucket>,
+
+    /// Warnings by the name of the innermost template in which they occurred.
+    ///
+    /// Warnings that occurred outside of any template are not included.
+    pub by_template: BTreeMap<String, Bucket>,
+
+    /// The greatest number of example page titles to keep for each bucket.
+    pub examples: usize,
+
+    /// The number of pages added to the report.
+    pub pages: usize,
+
+    /// The number of pages added to the report that have at least one warning.
+    pub pages_with_warnings: usize,
+
+    /// The total number of warnings.
+    pub warnings: usize,
+}
+
+impl Report {
+    /// Creates an empty report that keeps at most `examples` example page titles for each bucket.
+    #[must_use]
+    pub fn new(examples: usize) -> Self {
+        Report {
+            by_code: BTreeMap::new(),
+            by_language: BTreeMap::new(),
+            by_message: BTreeMap::new(),
+            by_section: BTreeMap::new(),
+            by_template: BTreeMap::new(),
+            examples,
+            pages: 0,
+            pages_with_warnings: 0,
+            warnings: 0,
+        }
+    }
+
+    /// Adds the warnings from the output of parsing the page with the title `title`.
+    pub fn add(&mut self, title: &str, output: &::Output) {
+        self.pages += 1;
+        if output.warnings.is_empty() {
+            return;
+        }
+        self.pages_with_warnings += 1;
+        self.warnings += output.warnings.len();
+        for warning in &output.warnings {
+            add_to_bucket(
+                &mut self.by_code,
+                self.examples,
+                &format!("{} {}", warning.code.code(), warning.code.name()),
+                title,
+            );
+            add_to_bucket(
+                &mut self.by_message,
+                self.examples,
+                &message_name(warning.message),
+                title,
+            );
+            if let Some(language) = &warning.language {
+                add_to_bucket(
+                    &mut self.by_language,
+                    self.examples,
+                    language.language_code().unwrap_or_else(|| language.name()),
+                    title,
+                );
+            }
+            if let Some(section) = &warning.section {
+                add_to_bucket(&mut self.by_section, self.examples, section, title);
+            }
+            if let Some(template) = &warning.template {
+                add_to_bucket(&mut self.by_template, self.examples, template, title);
+            }
+        }
+    }
+
+    /// Formats the report as JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        ::serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Formats the report as a Markdown document with one table for each property, with the most common values first.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            "# Warnings\n\n- Pages: {}\n- Pages with warnings: {}\n- Warnings: {}\n",
+            self.pages, self.pages_with_warnings, self.warnings
+        );
+        for (heading, column, buckets) in &[
+            ("By kind", "Kind", &self.by_message),
+            ("By code", "Code", &self.by_code),
+            ("By language", "Language", &self.by_language),
+            ("By section", "Section", &self.by_section),
+            ("By template", "Template", &self.by_template),
+        ] {
+            markdown.push_str(&format!(
+                "\n## {}\n\n| {} | Count | Examples |\n| --- | ---: | --- |\n",
+                heading, column
+            ));
+            let mut buckets = buckets.iter().collect::<Vec<_>>();
+            buckets.sort_by(|(key1, bucket1), (key2, bucket2)| {
+                bucket2.count.cmp(&bucket1.count).then(key1.cmp(key2))
+            });
+            for (key, bucket) in buckets {
+                markdown.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    escape_markdown(key),
+                    bucket.count,
+                    bucket
+                        .examples
+                        .iter()
+                        .map(|title| escape_markdown(title))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+        markdown
+    }
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Report::new(5)
+    }
+}
+
+fn add_to_bucket(buckets: &mut BTreeMap<String, Bucket>, examples: usize, key: &str, title: &str) {
+    let bucket = buckets.entry(key.to_string()).or_default();
+    bucket.count += 1;
+    if bucket.examples.len() < examples && !bucket.examples.iter().any(|example| example == title) {
+        bucket.examples.push(title.to_string());
+    }
+}
+
+// The name of the kind of warning as it's serialized, for example `section_empty`.
+fn message_name(message: ::WarningMessage) -> String {
+    match ::serde_json::to_value(message) {
+        Ok(::serde_json::Value::String(name)) => name,
+        _ => unreachable!(),
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('|', "\\|")
+}
@@ -13,7 +13,7 @@ pub fn parse_section<'a, T>(
         None => parse_section(context, output),
         Some(_) => {
             *output = Some(None);
-            ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
+            ::add_warning(context, heading_node, ::WarningCode::DuplicateSection);
             0
         }
     }
@@ -40,7 +40,7 @@ pub fn parse_section_nodes<'a, T>(
         }
         if output.is_none() {
             *output = Some(None);
-            ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+            ::add_warning(context, heading_node, ::WarningCode::EmptySection);
         }
         node_index
     })
@@ -88,7 +88,7 @@ macro_rules! parse_list_section_basic {
                 |context, node, output| {
                     if let ::Node::$node_type { items, .. } = node {
                         *output = Some(if output.is_some() {
-                            ::add_warning(context, node, ::WarningMessage::Duplicate);
+                            ::add_warning(context, node, ::WarningCode::DuplicateList);
                             None
                         } else {
                             parse_list(context, node, items)
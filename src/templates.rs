@@ -10,20 +10,20 @@ pub fn parse_template_labels<'a>(
     let mut labels = vec![];
     for parameter in parameters {
         if parameter.name.is_some() {
-            ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+            ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
             continue;
         }
         match ::parse_text_not_empty(&parameter.value) {
-            None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+            None => ::add_warning(context, parameter, ::WarningCode::InvalidParameterValue),
             Some(text) => if labels.iter().any(|label| label == &text) {
-                ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+                ::add_warning(context, parameter, ::WarningCode::DuplicateValue);
             } else {
                 labels.push(text);
             },
         }
     }
     if labels.is_empty() {
-        ::add_warning(context, template_node, ::WarningMessage::Empty);
+        ::add_warning(context, template_node, ::WarningCode::EmptyTemplate);
     }
     labels
 }
@@ -2,7 +2,7 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
-use std::collections::hash_map::Entry;
+use std::{collections::hash_map::Entry, hash::Hash};
 
 pub fn parse_translations<'a>(
     context: &mut ::Context<'a>,
@@ -16,6 +16,7 @@ pub fn parse_translations<'a>(
         nodes,
         output,
         |context, list_item| {
+            let mut other_translations = ::HashMap::new();
             let mut translations = ::HashMap::new();
             if let [::Node::Template {
                 name, parameters, ..
@@ -25,65 +26,93 @@ pub fn parse_translations<'a>(
                     let mut gloss = None;
                     for parameter in parameters {
                         if let Some(name) = ::parse_parameter_name(parameter) {
-                            if let Some(language) = ::Language::from_language_code(&name) {
-                                if language != context.language.unwrap() {
-                                    match translations.entry(language) {
-                                        Entry::Occupied(mut entry) => {
-                                            ::add_warning(
-                                                context,
-                                                parameter,
-                                                ::WarningMessage::Duplicate,
-                                            );
-                                            entry.insert(parse_translation(
-                                                context,
-                                                name,
-                                                &parameter.value,
-                                            ));
-                                        }
-                                        Entry::Vacant(entry) => {
-                                            entry.insert(parse_translation(
-                                                context,
-                                                name,
-                                                &parameter.value,
-                                            ));
-                                        }
-                                    }
+                            if let Some(language) = ::Language::from_language_code(name) {
+                                if context.language != Some(::EntryLanguage::Known(language)) {
+                                    insert_translation(
+                                        context,
+                                        &mut translations,
+                                        language,
+                                        parameter,
+                                        name,
+                                    );
+                                }
+                                continue;
+                            }
+                            if context.languages.name(name).is_some() {
+                                if context
+                                    .language
+                                    .as_ref()
+                                    .and_then(::EntryLanguage::language_code)
+                                    != Some(name)
+                                {
+                                    insert_translation(
+                                        context,
+                                        &mut other_translations,
+                                        ::Cow::Borrowed(name),
+                                        parameter,
+                                        name,
+                                    );
                                 }
                                 continue;
                             }
                             if name == "význam" {
                                 if gloss.is_some() {
-                                    ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+                                    ::add_warning(
+                                        context,
+                                        parameter,
+                                        ::WarningCode::DuplicateParameter,
+                                    );
                                 }
                                 let value = ::parse_text_not_empty(&parameter.value);
                                 if value.is_none() {
                                     ::add_warning(
                                         context,
                                         parameter,
-                                        ::WarningMessage::ValueUnrecognized,
+                                        ::WarningCode::InvalidParameterValue,
                                     );
                                 }
                                 gloss = Some(value);
                                 continue;
                             }
                         }
-                        ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                        ::add_warning(context, parameter, ::WarningCode::UnrecognizedParameter);
                     }
                     return ::Translations {
                         gloss: gloss.unwrap_or_default(),
+                        other_translations,
                         translations,
                     };
                 }
             }
-            ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized);
+            ::add_warning(context, list_item, ::WarningCode::InvalidTranslation);
             ::Translations {
                 gloss: None,
+                other_translations,
                 translations,
             }
         },
     )
 }
 
+fn insert_translation<'a, K: Eq + Hash>(
+    context: &mut ::Context<'a>,
+    translations: &mut ::HashMap<K, Vec<::Flowing<'a>>>,
+    key: K,
+    parameter: &::Parameter<'a>,
+    language: &str,
+) {
+    match translations.entry(key) {
+        Entry::Occupied(mut entry) => {
+            ::add_warning(context, parameter, ::WarningCode::DuplicateParameter);
+            entry.insert(parse_translation(context, language, &parameter.value));
+        }
+        Entry::Vacant(entry) => {
+            entry.insert(parse_translation(context, language, &parameter.value));
+        }
+    }
+}
+
+#[allow(clippy::collapsible_match)]
 fn parse_translation<'a>(
     context: &mut ::Context<'a>,
     language: &str,
@@ -131,6 +160,7 @@ fn parse_translation<'a>(
         .collect()
 }
 
+#[allow(clippy::collapsible_match)]
 fn parse_template_translation<'a>(
     outer_language: &str,
     parameters: &[::Parameter<'a>],
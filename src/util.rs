@@ -5,25 +5,55 @@
 use parse_wiki_text::Positioned;
 
 pub struct Context<'a> {
-    pub language: Option<::Language>,
+    pub form: bool,
+    pub language: Option<::EntryLanguage<'a>>,
+    pub language_sections: Vec<(usize, usize, ::EntryLanguage<'a>)>,
+    pub languages: ::LanguageRegistry,
+    pub pos: Option<::Pos>,
+    pub reference_indices: ::HashMap<usize, usize>,
+    pub references: Vec<Vec<::Flowing<'a>>>,
+    pub title: Option<&'a str>,
     pub warnings: Vec<::Warning>,
     pub wiki_text: &'a str,
 }
 
-pub fn add_warning(context: &mut Context, node: &impl Positioned, message: ::WarningMessage) {
+pub fn add_warning(context: &mut Context, node: &impl Positioned, code: ::WarningCode) {
     // This panics when accidentally making an infinite loop that produces warnings. This sometimes happens during development. In release builds, loops are assumed to already be tested and work properly.
     debug_assert!(context.warnings.len() < 10000);
+    let message = code.message();
     context.warnings.push(::Warning {
+        code,
         end: node.end(),
-        language: context.language,
+        language: context
+            .language
+            .clone()
+            .map(::owned::IntoStatic::into_static),
         message,
+        parameter: None,
+        pos: context.pos,
+        section: None,
+        severity: message.severity(),
         start: node.start(),
+        template: None,
     });
 }
 
+pub fn check_title(
+    context: &mut Context,
+    node: &impl Positioned,
+    code: ::WarningCode,
+    matches: impl FnOnce(&str) -> bool,
+) {
+    if let Some(title) = context.title {
+        if !matches(title) {
+            add_warning(context, node, code);
+        }
+    }
+}
+
 #[must_use]
 pub fn create_unknown<'a>(context: &mut Context<'a>, node: &::Node) -> ::Flowing<'a> {
-    create_unknown2(context, node, node, ::WarningMessage::Unrecognized)
+    create_unknown2(context, node, node, unrecognized_code(node))
 }
 
 #[must_use]
@@ -31,9 +61,9 @@ pub fn create_unknown2<'a>(
     context: &mut Context<'a>,
     unknown_node: &::Node,
     warning_node: &impl Positioned,
-    warning_message: ::WarningMessage,
+    warning_code: ::WarningCode,
 ) -> ::Flowing<'a> {
-    add_warning(context, warning_node, warning_message);
+    add_warning(context, warning_node, warning_code);
     ::Flowing::Unknown {
         value: ::Cow::Borrowed(&context.wiki_text[unknown_node.start()..unknown_node.end()]),
     }
@@ -96,16 +126,26 @@ pub fn text_equals(nodes: &[::Node], text: &str) -> bool {
     }
 }
 
+#[allow(clippy::collapsible_match)]
 pub fn unrecognized_unless_ignored(context: &mut Context, node: &::Node) {
     match node {
         ::Node::Category { .. } => return,
         ::Node::Template { name, .. } => if text_equals(name, "Doplnit") {
-            return add_warning(context, node, ::WarningMessage::Supplementary);
+            return add_warning(context, node, ::WarningCode::IncompleteEntry);
         },
-        ::Node::Text { value, .. } => if value.trim_left().is_empty() {
+        ::Node::Text { value, .. } => if value.trim_start().is_empty() {
             return;
         },
         _ => {}
     }
-    add_warning(context, node, ::WarningMessage::Unrecognized);
+    add_warning(context, node, unrecognized_code(node));
+}
+
+#[must_use]
+pub fn unrecognized_code(node: &::Node) -> ::WarningCode {
+    match node {
+        ::Node::Heading { .. } => ::WarningCode::UnrecognizedHeading,
+        ::Node::Template { .. } => ::WarningCode::UnrecognizedTemplate,
+        _ => ::WarningCode::UnrecognizedElement,
+    }
 }
@@ -0,0 +1,310 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+/// Identifier for a specific kind of problem found by the parser.
+///
+/// Each code belongs to one of the coarse kinds of [`WarningMessage`](enum.WarningMessage.html), but distinguishes the situations in which that kind of warning occurs. The codes are stable: the code and name of a variant never change, and codes of removed variants are never reused. The first two digits of the code identify the kind of warning.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WarningCode {
+    /// `CS0702 conflicting-details`: The details of a part of speech give more than one value for the same grammatical category.
+    ConflictingDetails,
+
+    /// `CS0701 conflicting-language`: The language given as a template parameter is not the language of the section.
+    ConflictingLanguage,
+
+    /// `CS0101 duplicate-language-section`: The page has more than one section for the same language.
+    DuplicateLanguageSection,
+
+    /// `CS0103 duplicate-list`: A section that takes a single list contains more than one list.
+    DuplicateList,
+
+    /// `CS0105 duplicate-parameter`: A template has more than one parameter with the same meaning.
+    DuplicateParameter,
+
+    /// `CS0102 duplicate-section`: A section has the same heading as a previous section at the same level.
+    DuplicateSection,
+
+    /// `CS0104 duplicate-template`: A template that may occur only once in its position occurs more than once.
+    DuplicateTemplate,
+
+    /// `CS0106 duplicate-value`: A template has the same value in more than one parameter where the values should be distinct.
+    DuplicateValue,
+
+    /// `CS0202 empty-definition`: A list item in the section `význam` has no definition text.
+    EmptyDefinition,
+
+    /// `CS0203 empty-list-item`: A list item has no content.
+    EmptyListItem,
+
+    /// `CS0204 empty-parameter`: A template parameter that should contain a list of terms contains none.
+    EmptyParameter,
+
+    /// `CS0301 empty-section`: A section has no content.
+    EmptySection,
+
+    /// `CS0201 empty-template`: A template has no parameters with content.
+    EmptyTemplate,
+
+    /// `CS0501 hyphenation-title-mismatch`: The hyphenation doesn't contain the same letters as the title of the page.
+    HyphenationTitleMismatch,
+
+    /// `CS0401 incomplete-entry`: The template `Doplnit` marks the entry as incomplete.
+    IncompleteEntry,
+
+    /// `CS0502 inflection-title-mismatch`: None of the basic forms of an inflection table is the title of the page.
+    InflectionTitleMismatch,
+
+    /// `CS0803 invalid-example`: An item in the list of examples of a definition is not a single template `Příklad`.
+    InvalidExample,
+
+    /// `CS0804 invalid-external-link`: An item in the section `externí odkazy` is not a recognized link template.
+    InvalidExternalLink,
+
+    /// `CS0811 invalid-form-of`: A definition in a section for word forms doesn't link to the lemma.
+    InvalidFormOf,
+
+    /// `CS0805 invalid-hyphenation`: The section `dělení` doesn't contain a single list item with plain text.
+    InvalidHyphenation,
+
+    /// `CS0801 invalid-parameter-value`: A template parameter doesn't contain plain text where plain text is expected.
+    InvalidParameterValue,
+
+    /// `CS0806 invalid-pronunciation`: An item in the section `výslovnost` doesn't have a recognized combination of templates.
+    InvalidPronunciation,
+
+    /// `CS0807 invalid-references`: The tag `references` has content.
+    InvalidReferences,
+
+    /// `CS0808 invalid-related-term`: An item in a list of related terms is not a single link.
+    InvalidRelatedTerm,
+
+    /// `CS0802 invalid-template-parameters`: A template doesn't have the expected number or combination of parameters.
+    InvalidTemplateParameters,
+
+    /// `CS0809 invalid-translation`: An item in the section `překlady` is not a single template `Překlady`.
+    InvalidTranslation,
+
+    /// `CS0302 missing-definitions`: The section for a part of speech has no section `význam`.
+    MissingDefinitions,
+
+    /// `CS0205 missing-parameter`: A template lacks a required parameter.
+    MissingParameter,
+
+    /// `CS0303 missing-pos-section`: The section for a language has no section for a part of speech.
+    MissingPosSection,
+
+    /// `CS0810 unknown-language`: The heading of a section for a language is not the name of a known language.
+    UnknownLanguage,
+
+    /// `CS0606 unrecognized-detail`: A line in the details of a part of speech starts with `rod` or `vid`, but its values are not recognized.
+    UnrecognizedDetail,
+
+    /// `CS0601 unrecognized-element`: An element is not recognized in its position.
+    UnrecognizedElement,
+
+    /// `CS0602 unrecognized-heading`: The heading of a section is not recognized in its position.
+    UnrecognizedHeading,
+
+    /// `CS0605 unrecognized-inflection-forms`: A parameter of an inflection table is not a list of forms separated by commas or slashes.
+    UnrecognizedInflectionForms,
+
+    /// `CS0603 unrecognized-parameter`: A template has a parameter with a name that is not recognized.
+    UnrecognizedParameter,
+
+    /// `CS0604 unrecognized-template`: A template is not recognized in its position.
+    UnrecognizedTemplate,
+
+    /// `CS0206 unresolved-reference`: A tag `ref` refers by name to a reference that doesn't exist on the page.
+    UnresolvedReference,
+}
+
+impl WarningCode {
+    /// Returns all warning codes, in the order of their codes.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[
+            WarningCode::DuplicateLanguageSection,
+            WarningCode::DuplicateSection,
+            WarningCode::DuplicateList,
+            WarningCode::DuplicateTemplate,
+            WarningCode::DuplicateParameter,
+            WarningCode::DuplicateValue,
+            WarningCode::EmptyTemplate,
+            WarningCode::EmptyDefinition,
+            WarningCode::EmptyListItem,
+            WarningCode::EmptyParameter,
+            WarningCode::MissingParameter,
+            WarningCode::UnresolvedReference,
+            WarningCode::EmptySection,
+            WarningCode::MissingDefinitions,
+            WarningCode::MissingPosSection,
+            WarningCode::IncompleteEntry,
+            WarningCode::HyphenationTitleMismatch,
+            WarningCode::InflectionTitleMismatch,
+            WarningCode::UnrecognizedElement,
+            WarningCode::UnrecognizedHeading,
+            WarningCode::UnrecognizedParameter,
+            WarningCode::UnrecognizedTemplate,
+            WarningCode::UnrecognizedInflectionForms,
+            WarningCode::UnrecognizedDetail,
+            WarningCode::ConflictingLanguage,
+            WarningCode::ConflictingDetails,
+            WarningCode::InvalidParameterValue,
+            WarningCode::InvalidTemplateParameters,
+            WarningCode::InvalidExample,
+            WarningCode::InvalidExternalLink,
+            WarningCode::InvalidHyphenation,
+            WarningCode::InvalidPronunciation,
+            WarningCode::InvalidReferences,
+            WarningCode::InvalidRelatedTerm,
+            WarningCode::InvalidTranslation,
+            WarningCode::UnknownLanguage,
+            WarningCode::InvalidFormOf,
+        ]
+    }
+
+    /// Returns the stable code, for example `CS0101`.
+    #[must_use]
+    pub fn code(self) -> &'static str {
+        match self {
+            WarningCode::ConflictingDetails => "CS0702",
+            WarningCode::ConflictingLanguage => "CS0701",
+            WarningCode::DuplicateLanguageSection => "CS0101",
+            WarningCode::DuplicateList => "CS0103",
+            WarningCode::DuplicateParameter => "CS0105",
+            WarningCode::DuplicateSection => "CS0102",
+            WarningCode::DuplicateTemplate => "CS0104",
+            WarningCode::DuplicateValue => "CS0106",
+            WarningCode::EmptyDefinition => "CS0202",
+            WarningCode::EmptyListItem => "CS0203",
+            WarningCode::EmptyParameter => "CS0204",
+            WarningCode::EmptySection => "CS0301",
+            WarningCode::EmptyTemplate => "CS0201",
+            WarningCode::HyphenationTitleMismatch => "CS0501",
+            WarningCode::IncompleteEntry => "CS0401",
+            WarningCode::InflectionTitleMismatch => "CS0502",
+            WarningCode::InvalidExample => "CS0803",
+            WarningCode::InvalidExternalLink => "CS0804",
+            WarningCode::InvalidFormOf => "CS0811",
+            WarningCode::InvalidHyphenation => "CS0805",
+            WarningCode::InvalidParameterValue => "CS0801",
+            WarningCode::InvalidPronunciation => "CS0806",
+            WarningCode::InvalidReferences => "CS0807",
+            WarningCode::InvalidRelatedTerm => "CS0808",
+            WarningCode::InvalidTemplateParameters => "CS0802",
+            WarningCode::InvalidTranslation => "CS0809",
+            WarningCode::MissingDefinitions => "CS0302",
+            WarningCode::MissingParameter => "CS0205",
+            WarningCode::MissingPosSection => "CS0303",
+            WarningCode::UnknownLanguage => "CS0810",
+            WarningCode::UnrecognizedDetail => "CS0606",
+            WarningCode::UnrecognizedElement => "CS0601",
+            WarningCode::UnrecognizedHeading => "CS0602",
+            WarningCode::UnrecognizedInflectionForms => "CS0605",
+            WarningCode::UnrecognizedParameter => "CS0603",
+            WarningCode::UnrecognizedTemplate => "CS0604",
+            WarningCode::UnresolvedReference => "CS0206",
+        }
+    }
+
+    /// Returns the warning code corresponding to the given code, for example `CS0101`, or the given name, for example `duplicate-language-section`, if any.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        WarningCode::all()
+            .iter()
+            .cloned()
+            .find(|warning_code| warning_code.code() == code || warning_code.name() == code)
+    }
+
+    /// Returns the coarse kind of warning the code belongs to.
+    #[must_use]
+    pub fn message(self) -> ::WarningMessage {
+        match self {
+            WarningCode::DuplicateLanguageSection
+            | WarningCode::DuplicateList
+            | WarningCode::DuplicateParameter
+            | WarningCode::DuplicateSection
+            | WarningCode::DuplicateTemplate
+            | WarningCode::DuplicateValue => ::WarningMessage::Duplicate,
+            WarningCode::EmptyDefinition
+            | WarningCode::EmptyListItem
+            | WarningCode::EmptyParameter
+            | WarningCode::EmptyTemplate
+            | WarningCode::MissingParameter
+            | WarningCode::UnresolvedReference => ::WarningMessage::Empty,
+            WarningCode::EmptySection
+            | WarningCode::MissingDefinitions
+            | WarningCode::MissingPosSection => ::WarningMessage::SectionEmpty,
+            WarningCode::IncompleteEntry => ::WarningMessage::Supplementary,
+            WarningCode::HyphenationTitleMismatch | WarningCode::InflectionTitleMismatch => {
+                ::WarningMessage::TitleMismatch
+            }
+            WarningCode::UnrecognizedDetail
+            | WarningCode::UnrecognizedElement
+            | WarningCode::UnrecognizedHeading
+            | WarningCode::UnrecognizedInflectionForms
+            | WarningCode::UnrecognizedParameter
+            | WarningCode::UnrecognizedTemplate => ::WarningMessage::Unrecognized,
+            WarningCode::ConflictingDetails | WarningCode::ConflictingLanguage => {
+                ::WarningMessage::ValueConflicting
+            }
+            WarningCode::InvalidExample
+            | WarningCode::InvalidExternalLink
+            | WarningCode::InvalidFormOf
+            | WarningCode::InvalidHyphenation
+            | WarningCode::InvalidParameterValue
+            | WarningCode::InvalidPronunciation
+            | WarningCode::InvalidReferences
+            | WarningCode::InvalidRelatedTerm
+            | WarningCode::InvalidTemplateParameters
+            | WarningCode::InvalidTranslation
+            | WarningCode::UnknownLanguage => ::WarningMessage::ValueUnrecognized,
+        }
+    }
+
+    /// Returns the stable name, for example `duplicate-language-section`.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            WarningCode::ConflictingDetails => "conflicting-details",
+            WarningCode::ConflictingLanguage => "conflicting-language",
+            WarningCode::DuplicateLanguageSection => "duplicate-language-section",
+            WarningCode::DuplicateList => "duplicate-list",
+            WarningCode::DuplicateParameter => "duplicate-parameter",
+            WarningCode::DuplicateSection => "duplicate-section",
+            WarningCode::DuplicateTemplate => "duplicate-template",
+            WarningCode::DuplicateValue => "duplicate-value",
+            WarningCode::EmptyDefinition => "empty-definition",
+            WarningCode::EmptyListItem => "empty-list-item",
+            WarningCode::EmptyParameter => "empty-parameter",
+            WarningCode::EmptySection => "empty-section",
+            WarningCode::EmptyTemplate => "empty-template",
+            WarningCode::HyphenationTitleMismatch => "hyphenation-title-mismatch",
+            WarningCode::IncompleteEntry => "incomplete-entry",
+            WarningCode::InflectionTitleMismatch => "inflection-title-mismatch",
+            WarningCode::InvalidExample => "invalid-example",
+            WarningCode::InvalidExternalLink => "invalid-external-link",
+            WarningCode::InvalidFormOf => "invalid-form-of",
+            WarningCode::InvalidHyphenation => "invalid-hyphenation",
+            WarningCode::InvalidParameterValue => "invalid-parameter-value",
+            WarningCode::InvalidPronunciation => "invalid-pronunciation",
+            WarningCode::InvalidReferences => "invalid-references",
+            WarningCode::InvalidRelatedTerm => "invalid-related-term",
+            WarningCode::InvalidTemplateParameters => "invalid-template-parameters",
+            WarningCode::InvalidTranslation => "invalid-translation",
+            WarningCode::MissingDefinitions => "missing-definitions",
+            WarningCode::MissingParameter => "missing-parameter",
+            WarningCode::MissingPosSection => "missing-pos-section",
+            WarningCode::UnknownLanguage => "unknown-language",
+            WarningCode::UnrecognizedDetail => "unrecognized-detail",
+            WarningCode::UnrecognizedElement => "unrecognized-element",
+            WarningCode::UnrecognizedHeading => "unrecognized-heading",
+            WarningCode::UnrecognizedInflectionForms => "unrecognized-inflection-forms",
+            WarningCode::UnrecognizedParameter => "unrecognized-parameter",
+            WarningCode::UnrecognizedTemplate => "unrecognized-template",
+            WarningCode::UnresolvedReference => "unresolved-reference",
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+extern crate parse_wiktionary_cs;
+extern crate serde_json;
+
+use std::io::{self, Write};
+
+struct FailingWriter {
+    writes: usize,
+}
+
+impl Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        self.writes += 1;
+        Err(io::Error::other("failed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn create_pages() -> Vec<(String, String)> {
+    (0..100)
+        .map(|index| {
+            let mut wiki_text = String::new();
+            for _ in 0..index % 7 {
+                wiki_text += "==čeština==\n===sloveso===\n====význam====\n#definice\n";
+            }
+            (format!("strana {}", index), wiki_text)
+        })
+        .collect()
+}
+
+#[test]
+fn parse() {
+    let configuration = parse_wiktionary_cs::create_configuration();
+    let pages = create_pages();
+    let mut outputs = vec![];
+    parse_wiktionary_cs::batch::parse(
+        &configuration,
+        &parse_wiktionary_cs::ParseOptions::default(),
+        4,
+        pages
+            .iter()
+            .map(|(title, wiki_text)| (title.as_str(), wiki_text.as_str())),
+        |result| {
+            let output = result.unwrap();
+            outputs.push((
+                output.title.unwrap().into_owned(),
+                output.language_entries.len(),
+            ))
+        },
+    );
+    assert_eq!(
+        outputs,
+        pages
+            .iter()
+            .enumerate()
+            .map(|(index, (title, _))| (title.clone(), index % 7))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn parse_json_lines() {
+    let configuration = parse_wiktionary_cs::create_configuration();
+    let pages = create_pages();
+    let mut expected = String::new();
+    for (title, wiki_text) in &pages {
+        let output = parse_wiktionary_cs::parse_page(
+            title,
+            wiki_text,
+            &configuration.parse(wiki_text).nodes,
+        );
+        expected += &serde_json::to_string(&output).unwrap();
+        expected.push('\n');
+    }
+    let mut actual = vec![];
+    parse_wiktionary_cs::batch::parse_json_lines(
+        &configuration,
+        &parse_wiktionary_cs::ParseOptions::default(),
+        0,
+        pages,
+        &mut actual,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(actual).unwrap(), expected);
+}
+
+#[test]
+fn parse_json_lines_write_error() {
+    let configuration = parse_wiktionary_cs::create_configuration();
+    let mut writer = FailingWriter { writes: 0 };
+    let error = parse_wiktionary_cs::batch::parse_json_lines(
+        &configuration,
+        &parse_wiktionary_cs::ParseOptions::default(),
+        4,
+        create_pages(),
+        &mut writer,
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), "failed");
+    assert_eq!(writer.writes, 1);
+}
+
+#[test]
+fn parse_options() {
+    let configuration = parse_wiktionary_cs::create_configuration();
+    let mut options = parse_wiktionary_cs::ParseOptions::default();
+    options.languages.register("nesmysl", "xx");
+    options.strict = true;
+    let pages = [
+        ("pes", "==nesmysl==\n===podstatné jméno===\n====význam====\n#pes\n"),
+        ("kočka", "==nesmysl==\n===podstatné jméno===\n====nesmysl====\n"),
+    ];
+    let mut actual = vec![];
+    parse_wiktionary_cs::batch::parse_json_lines(&configuration, &options, 2, pages, &mut actual)
+        .unwrap();
+    let lines = String::from_utf8(actual)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect::<Vec<serde_json::Value>>();
+    assert_eq!(lines[0]["title"], "pes");
+    assert_eq!(lines[0]["language_entries"][0]["language"]["code"], "xx");
+    let fatal_warnings = lines[1]["fatal_warnings"].as_array().unwrap();
+    assert!(!fatal_warnings.is_empty());
+    for warning in fatal_warnings {
+        assert_eq!(
+            warning["language"],
+            serde_json::json!({ "code": "xx", "name": "nesmysl" })
+        );
+    }
+}
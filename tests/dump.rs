@@ -0,0 +1,73 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+extern crate parse_wiktionary_cs;
+
+use parse_wiktionary_cs::dump;
+
+fn check_pages(pages: dump::Pages<impl std::io::BufRead>) {
+    let pages = pages.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(pages.len(), 3);
+    assert_eq!(pages[0].title, "švejkovat");
+    assert_eq!(pages[0].namespace, 0);
+    assert_eq!(pages[0].revision_id, 1073520);
+    assert!(pages[0].text.starts_with("==čeština==\n"));
+    assert!(
+        pages[0]
+            .text
+            .contains("příkazu & povinnosti<ref>Zdroj</ref>\n")
+    );
+    assert_eq!(pages[1].title, "Šablona:Viz");
+    assert_eq!(pages[1].namespace, 10);
+    assert_eq!(pages[1].revision_id, 998877);
+    assert_eq!(pages[1].text, "text");
+    assert_eq!(pages[2].title, "prázdná");
+    assert_eq!(pages[2].text, "");
+}
+
+#[test]
+fn read_uncompressed() {
+    check_pages(dump::open("tests/fixtures/dump.xml").unwrap());
+}
+
+#[cfg(feature = "bzip2")]
+#[test]
+fn read_bzip2() {
+    check_pages(dump::open("tests/fixtures/dump.xml.bz2").unwrap());
+}
+
+#[cfg(not(feature = "bzip2"))]
+#[test]
+fn read_bzip2_disabled() {
+    match dump::open("tests/fixtures/dump.xml.bz2") {
+        Err(dump::Error::Bzip2Disabled) => {}
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn read_truncated() {
+    let mut pages = dump::Pages::new("<mediawiki><page><title>a</title>".as_bytes());
+    match pages.next() {
+        Some(Err(dump::Error::Format(_))) => {}
+        _ => panic!(),
+    }
+    assert!(pages.next().is_none());
+}
+
+#[test]
+fn parse_pages() {
+    let mut titles = vec![];
+    dump::parse_pages(
+        dump::open("tests/fixtures/dump.xml").unwrap(),
+        |page, output| {
+            titles.push(page.title.clone());
+            if page.title == "švejkovat" {
+                assert_eq!(output.language_entries.len(), 1);
+                assert_eq!(output.language_entries[0].pos_entries[0].definitions.len(), 1);
+            }
+        },
+    ).unwrap();
+    assert_eq!(titles, ["švejkovat", "prázdná"]);
+}
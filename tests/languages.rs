@@ -0,0 +1,116 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+extern crate parse_wiki_text;
+extern crate parse_wiktionary_cs;
+
+use parse_wiktionary_cs::Language;
+
+#[test]
+fn round_trip() {
+    assert!(Language::all().len() > 300);
+    for &language in Language::all() {
+        assert_eq!(
+            Language::from_language_code(language.language_code()),
+            Some(language)
+        );
+        assert_eq!(language.language_code().parse(), Ok(language));
+        // Tahitian has two language codes but a single name.
+        let named_language = if language == Language::Tah {
+            Language::Ty
+        } else {
+            language
+        };
+        assert_eq!(Language::from_name(language.name()), Some(named_language));
+        assert_eq!(language.to_string().parse(), Ok(named_language));
+    }
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("cs".parse(), Ok(Language::Cs));
+    assert_eq!("čeština".parse(), Ok(Language::Cs));
+    assert_eq!(Language::Cs.to_string(), "čeština");
+    assert_eq!(
+        "nesmysl".parse::<Language>(),
+        Err(parse_wiktionary_cs::ParseLanguageError)
+    );
+}
+
+#[test]
+fn metadata() {
+    for &language in Language::all() {
+        let tag = language.bcp47_tag();
+        let primary = tag.split('-').next().unwrap();
+        assert!(primary.len() == 2 || primary.len() == 3);
+        if let Some(code) = language.iso_639_1() {
+            assert_eq!(code, primary);
+        }
+        if let Some(code) = language.iso_639_3() {
+            assert_eq!(code.len(), 3);
+            assert!(code.chars().all(|character| character.is_ascii_lowercase()));
+        }
+        let script = language.script();
+        assert_eq!(script.len(), 4);
+        assert!(script.starts_with(|character: char| character.is_ascii_uppercase()));
+    }
+    assert_eq!(Language::Cs.iso_639_1(), Some("cs"));
+    assert_eq!(Language::Cs.iso_639_3(), Some("ces"));
+    assert_eq!(
+        Language::Cs.family(),
+        parse_wiktionary_cs::LanguageFamily::IndoEuropean
+    );
+    // The Wiktionary codes `chk` and `als` stand for Chukchi and Alsatian, not for Chuukese and Tosk Albanian.
+    assert_eq!(Language::Chk.iso_639_3(), Some("ckt"));
+    assert_eq!(Language::Als.bcp47_tag(), "gsw");
+    assert_eq!(Language::SrLatn.bcp47_tag(), "sr-Latn");
+    assert_eq!(Language::SrLatn.script(), "Latn");
+    assert_eq!(Language::Nah.iso_639_3(), None);
+}
+
+#[test]
+fn registry() {
+    let mut options = parse_wiktionary_cs::ParseOptions::default();
+    assert!(options.languages.is_empty());
+    options.languages.register("nesmysl", "xx");
+    options.languages.register("jiný nesmysl", "xy");
+    options.languages.register("starý nesmysl", "xy");
+    assert_eq!(options.languages.language_code("nesmysl"), Some("xx"));
+    assert_eq!(options.languages.language_code("jiný nesmysl"), None);
+    assert_eq!(options.languages.name("xy"), Some("starý nesmysl"));
+    let wiki_text = concat!(
+        "==nesmysl==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#{{Příznaky|xx|hovor.}} pes\n",
+        "====překlady====\n",
+        "#{{Překlady\n",
+        "|cs={{P|cs|pes}}\n",
+        "|xx={{P|xx|pes}}\n",
+        "|xy={{P|xy|pes}}\n",
+        "}}\n"
+    );
+    let nodes = parse_wiktionary_cs::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output =
+        parse_wiktionary_cs::parse_with_options(None, wiki_text, &nodes, &options).unwrap();
+    assert!(output.warnings.is_empty());
+    let language_entry = &output.language_entries[0];
+    assert_eq!(language_entry.language.language_code(), Some("xx"));
+    assert_eq!(language_entry.language.name(), "nesmysl");
+    let pos_entry = &language_entry.pos_entries[0];
+    assert_eq!(pos_entry.definitions[0].labels, ["hovor."]);
+    let translations = &pos_entry.translations[0];
+    assert_eq!(translations.translations.len(), 1);
+    assert!(translations.translations.contains_key(&Language::Cs));
+    assert_eq!(translations.other_translations.len(), 1);
+    assert!(translations.other_translations.contains_key("xy"));
+    let output = parse_wiktionary_cs::parse(wiki_text, &nodes);
+    assert_eq!(
+        output.warnings[0].code,
+        parse_wiktionary_cs::WarningCode::UnknownLanguage
+    );
+    assert_eq!(output.language_entries[0].language.language_code(), None);
+}
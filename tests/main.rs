@@ -4,6 +4,7 @@
 
 extern crate parse_wiki_text;
 extern crate parse_wiktionary_cs;
+extern crate serde_json;
 
 #[test]
 fn main() {
@@ -12,3 +13,792 @@ fn main() {
         &parse_wiktionary_cs::create_configuration().parse("").nodes,
     );
 }
+
+#[test]
+fn into_owned() {
+    let output = {
+        let wiki_text = String::from("==čeština==\n===sloveso===\n====význam====\n#definice\n");
+        parse_wiktionary_cs::parse(
+            &wiki_text,
+            &parse_wiktionary_cs::create_configuration()
+                .parse(&wiki_text)
+                .nodes,
+        ).into_owned()
+    };
+    let output: parse_wiktionary_cs::Output<'static> = output;
+    match output.language_entries[0].pos_entries[0].definitions[0].definition[0] {
+        parse_wiktionary_cs::Flowing::Text { ref value } => assert_eq!(value, "definice"),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn parse_page() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===dělení===\n",
+        "* ško-la\n",
+        "===podstatné jméno===\n",
+        "====skloňování====\n",
+        "{{Substantivum (cs)\n",
+        "|snom=škola\n",
+        "|pnom=školy\n",
+        "}}\n",
+        "====význam====\n",
+        "#instituce\n"
+    );
+    let nodes = parse_wiktionary_cs::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let count_mismatches = |output: parse_wiktionary_cs::Output| {
+        output
+            .warnings
+            .iter()
+            .filter(|warning| {
+                warning.message == parse_wiktionary_cs::WarningMessage::TitleMismatch
+            })
+            .count()
+    };
+    let output = parse_wiktionary_cs::parse_page("škola", wiki_text, &nodes);
+    assert_eq!(output.title.as_ref().unwrap(), "škola");
+    assert_eq!(count_mismatches(output), 0);
+    assert_eq!(
+        count_mismatches(parse_wiktionary_cs::parse_page("školy", wiki_text, &nodes)),
+        1
+    );
+    assert_eq!(
+        count_mismatches(parse_wiktionary_cs::parse_page("skola", wiki_text, &nodes)),
+        2
+    );
+    assert_eq!(
+        count_mismatches(parse_wiktionary_cs::parse(wiki_text, &nodes)),
+        0
+    );
+}
+
+#[test]
+fn redirect() {
+    let wiki_text = "#PŘESMĚRUJ [[Praha#čeština]]\n[[Kategorie:Přesměrování]]\n";
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let redirect = output.redirect.unwrap();
+    assert_eq!(redirect.target, "Praha");
+    assert_eq!(redirect.anchor.unwrap(), "čeština");
+    assert!(output.language_entries.is_empty());
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn see_also() {
+    let wiki_text = "{{Viz|Praha|práh}}\n==čeština==\n===podstatné jméno===\n====význam====\n#město\n";
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.see_also, ["Praha", "práh"]);
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn notes_and_references() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#město<ref name=\"slovník\">[[Slovník]]</ref>\n",
+        "#*{{Příklad|cs|Praha je město.}}<ref name=\"slovník\" />\n",
+        "#obec<ref>Jiný zdroj</ref>\n",
+        "#vesnice<ref group=\"names\" name=vesnice>Třetí zdroj</ref>\n",
+        "#ves<ref group=names name=vesnice/>\n",
+        "== poznámky ==\n",
+        "* ''Poznámka'' o [[slovo|slově]]\n",
+        "<references />\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    assert_eq!(output.references.len(), 3);
+    assert_eq!(output.notes.len(), 1);
+    assert_eq!(output.notes[0].len(), 5);
+    let definitions = &output.language_entries[0].pos_entries[0].definitions;
+    assert_eq!(definitions[0].references, [0]);
+    assert_eq!(definitions[0].examples[0].example, "Praha je město.");
+    assert_eq!(definitions[0].examples[0].references, [0]);
+    assert_eq!(definitions[1].references, [1]);
+    assert_eq!(definitions[2].references, [2]);
+    assert_eq!(definitions[3].references, [2]);
+}
+
+#[test]
+fn reference_warnings() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "====nesmysl====\n",
+        "====význam====\n",
+        "#město<ref>{{Nesmysl}}</ref>\n",
+        "#obec<ref name=\"chybí\" />\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(
+        output
+            .warnings
+            .iter()
+            .map(|warning| warning.code)
+            .collect::<Vec<_>>(),
+        [
+            parse_wiktionary_cs::WarningCode::UnrecognizedHeading,
+            parse_wiktionary_cs::WarningCode::UnrecognizedTemplate,
+            parse_wiktionary_cs::WarningCode::UnresolvedReference,
+        ]
+    );
+    for warning in &output.warnings {
+        assert_eq!(
+            warning.language,
+            Some(parse_wiktionary_cs::EntryLanguage::Known(
+                parse_wiktionary_cs::Language::Cs
+            ))
+        );
+    }
+    assert_eq!(
+        output.warnings[2].message,
+        parse_wiktionary_cs::WarningMessage::Empty
+    );
+}
+
+#[test]
+fn strict() {
+    let wiki_text = "==čeština==\n===sloveso===\n====význam====\n#definice {{Doplnit}}\n";
+    let nodes = parse_wiktionary_cs::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let mut options = parse_wiktionary_cs::ParseOptions::default();
+    assert!(parse_wiktionary_cs::parse_with_options(None, wiki_text, &nodes, &options).is_ok());
+    options.strict = true;
+    let warnings =
+        parse_wiktionary_cs::parse_with_options(None, wiki_text, &nodes, &options).unwrap_err();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        parse_wiktionary_cs::WarningMessage::Unrecognized
+    );
+    options
+        .fatal_warnings
+        .remove(&parse_wiktionary_cs::WarningMessage::Unrecognized);
+    assert!(parse_wiktionary_cs::parse_with_options(None, wiki_text, &nodes, &options).is_ok());
+}
+
+#[test]
+fn warning_location() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#město\n",
+        "====překlady====\n",
+        "#{{Překlady\n",
+        "|en={{P|en|town}}\n",
+        "|neznámý=x\n",
+        "}}\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 1);
+    let warning = &output.warnings[0];
+    assert_eq!(warning.pos, Some(parse_wiktionary_cs::Pos::Noun));
+    assert_eq!(warning.section.as_ref().unwrap(), "překlady");
+    assert_eq!(warning.template.as_ref().unwrap(), "Překlady");
+    assert_eq!(warning.parameter.as_ref().unwrap(), "neznámý");
+}
+
+#[test]
+fn render_warning() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#město\n",
+        "====nesmysl====\n",
+        "<b>\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let warning = &output.warnings[0];
+    assert_eq!(warning.line_column(wiki_text), (5, 1));
+    assert_eq!(
+        warning.render(wiki_text, &Default::default()),
+        concat!(
+            "error[CS0602]: unrecognized-heading\n",
+            " --> 5:1\n",
+            "  = language: cs, pos: Noun, section: nesmysl\n",
+            "  |\n",
+            "2 | ===podstatné jméno===\n",
+            "3 | ====význam====\n",
+            "4 | #město\n",
+            "5 | ====nesmysl====\n",
+            "  | ^^^^^^^^^^^^^^^\n",
+            "6 | <b>\n"
+        )
+    );
+    let html = warning.render(
+        wiki_text,
+        &parse_wiktionary_cs::RenderOptions {
+            context_lines: 0,
+            format: parse_wiktionary_cs::RenderFormat::Html,
+            ..Default::default()
+        },
+    );
+    assert!(html.contains("5 | <mark>====nesmysl====</mark>\n"));
+    assert!(!html.contains("<b>"));
+}
+
+#[test]
+fn render_wiki_text_warning() {
+    let wiki_text = "==čeština==\n<ref>x";
+    let result = parse_wiktionary_cs::create_configuration().parse(wiki_text);
+    assert_eq!(
+        parse_wiktionary_cs::render_wiki_text_warning(
+            &result.warnings[0],
+            wiki_text,
+            &Default::default()
+        ),
+        concat!(
+            "warning: Missing end tag. Rewinding.\n",
+            " --> 2:1\n",
+            "  |\n",
+            "1 | ==čeština==\n",
+            "2 | <ref>x\n",
+            "  | ^^^^^^\n"
+        )
+    );
+}
+
+#[test]
+fn severity() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#město\n",
+        "{{Doplnit}}\n",
+        "====nesmysl====\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.has_errors());
+    let info = output
+        .warnings_with_severity(parse_wiktionary_cs::Severity::Info)
+        .collect::<Vec<_>>();
+    assert_eq!(info.len(), 1);
+    assert_eq!(
+        info[0].message,
+        parse_wiktionary_cs::WarningMessage::Supplementary
+    );
+    for warning in output.warnings_with_severity(parse_wiktionary_cs::Severity::Error) {
+        assert_eq!(warning.severity, warning.message.severity());
+        assert_ne!(warning.message, parse_wiktionary_cs::WarningMessage::Supplementary);
+    }
+}
+
+#[test]
+fn warning_codes() {
+    let codes = parse_wiktionary_cs::WarningCode::all();
+    for (index, code) in codes.iter().enumerate() {
+        assert_eq!(
+            parse_wiktionary_cs::WarningCode::from_code(code.code()),
+            Some(*code)
+        );
+        assert_eq!(
+            parse_wiktionary_cs::WarningCode::from_code(code.name()),
+            Some(*code)
+        );
+        assert_eq!(
+            serde_json::to_value(code).unwrap(),
+            serde_json::Value::String(code.name().to_string())
+        );
+        for other in &codes[index + 1..] {
+            assert!(code.code() < other.code());
+            assert_ne!(code.name(), other.name());
+            // The variants are declared in alphabetical order, which the derived ordering follows.
+            assert_eq!(code < other, code.name() < other.name());
+        }
+    }
+    let wiki_text = "==čeština==\n===podstatné jméno===\n====význam====\n#pes\n==čeština==\n";
+    let nodes = parse_wiktionary_cs::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_cs::parse(wiki_text, &nodes);
+    assert_eq!(
+        output
+            .warnings
+            .iter()
+            .map(|warning| warning.code)
+            .collect::<Vec<_>>(),
+        [
+            parse_wiktionary_cs::WarningCode::DuplicateLanguageSection,
+            parse_wiktionary_cs::WarningCode::MissingPosSection,
+        ]
+    );
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_cs::WarningMessage::Duplicate
+    );
+    let options = parse_wiktionary_cs::ParseOptions {
+        strict: true,
+        suppressed_warnings: [
+            parse_wiktionary_cs::WarningCode::DuplicateLanguageSection,
+            parse_wiktionary_cs::WarningCode::MissingPosSection,
+        ]
+            .iter()
+            .cloned()
+            .collect(),
+        ..Default::default()
+    };
+    let output =
+        parse_wiktionary_cs::parse_with_options(None, wiki_text, &nodes, &options).unwrap();
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn explanation() {
+    for code in parse_wiktionary_cs::WarningCode::all() {
+        for locale in &[
+            parse_wiktionary_cs::Locale::Cs,
+            parse_wiktionary_cs::Locale::En,
+        ] {
+            let explanation = code.explanation(*locale);
+            assert!(explanation.description.ends_with('.'));
+            assert!(explanation.fix.ends_with('.'));
+        }
+        assert_ne!(
+            code.explanation(parse_wiktionary_cs::Locale::Cs),
+            code.explanation(parse_wiktionary_cs::Locale::En)
+        );
+    }
+    let wiki_text = "==čeština==\n";
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let rendered = output.warnings[0].render(
+        wiki_text,
+        &parse_wiktionary_cs::RenderOptions {
+            explanation: Some(parse_wiktionary_cs::Locale::En),
+            ..Default::default()
+        },
+    );
+    assert!(rendered.ends_with(concat!(
+        "  = note: The section for the language has no section for a part of speech.\n",
+        "  = help: Add a section for a part of speech, such as podstatné jméno, with the definitions.\n"
+    )));
+}
+
+#[test]
+fn unknown_language() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#pes\n",
+        "==nesmysl==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#{{Příznaky|xx|hovor.}} pes\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.warnings[0].code,
+        parse_wiktionary_cs::WarningCode::UnknownLanguage
+    );
+    assert_eq!(output.warnings[0].language, None);
+    assert_eq!(output.language_entries.len(), 2);
+    let language = &output.language_entries[1].language;
+    assert_eq!(language.known(), None);
+    assert_eq!(language.language_code(), None);
+    assert_eq!(language.name(), "nesmysl");
+    assert_eq!(
+        output.language_entries[1].pos_entries[0].definitions[0].labels,
+        ["hovor."]
+    );
+    assert_eq!(
+        serde_json::to_value(&output.language_entries[0].language).unwrap(),
+        serde_json::json!("cs")
+    );
+    assert_eq!(
+        serde_json::to_value(language).unwrap(),
+        serde_json::json!({ "name": "nesmysl" })
+    );
+}
+
+#[test]
+fn homonym_index() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===sloveso===\n",
+        "====význam====\n",
+        "#jít\n",
+        "===sloveso (6)===\n",
+        "====význam====\n",
+        "#běžet\n",
+        "===podstatné jméno(12)===\n",
+        "====význam====\n",
+        "#pes\n",
+        "===etymologie (2)===\n",
+        "===sloveso (0)===\n",
+        "===sloveso (+1)===\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let pos_entries = &output.language_entries[0].pos_entries;
+    assert_eq!(
+        pos_entries
+            .iter()
+            .map(|pos_entry| (pos_entry.pos, pos_entry.homonym_index))
+            .collect::<Vec<_>>(),
+        [
+            (parse_wiktionary_cs::Pos::Verb, None),
+            (parse_wiktionary_cs::Pos::Verb, Some(6)),
+            (parse_wiktionary_cs::Pos::Noun, Some(12)),
+        ]
+    );
+    assert_eq!(output.warnings.len(), 3);
+    for warning in &output.warnings {
+        assert_eq!(
+            warning.code,
+            parse_wiktionary_cs::WarningCode::UnrecognizedHeading
+        );
+    }
+}
+
+#[test]
+fn pos_headings() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===vlastní jméno===\n",
+        "====skloňování====\n",
+        "{{Substantivum (cs)\n",
+        "|snom=Praha\n",
+        "}}\n",
+        "====význam====\n",
+        "#město\n",
+        "===značka===\n",
+        "====význam====\n",
+        "#symbol\n",
+        "===ustálené spojení (2)===\n",
+        "====význam====\n",
+        "#spojení\n",
+        "===fráze===\n",
+        "====význam====\n",
+        "#fráze\n",
+        "===znak===\n",
+        "====význam====\n",
+        "#znak\n"
+    );
+    let output = parse_wiktionary_cs::parse_page(
+        "Praha",
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let pos_entries = &output.language_entries[0].pos_entries;
+    assert_eq!(
+        pos_entries
+            .iter()
+            .map(|pos_entry| pos_entry.pos)
+            .collect::<Vec<_>>(),
+        [
+            parse_wiktionary_cs::Pos::ProperNoun,
+            parse_wiktionary_cs::Pos::Symbol,
+            parse_wiktionary_cs::Pos::FixedExpression,
+            parse_wiktionary_cs::Pos::Phrase,
+            parse_wiktionary_cs::Pos::Character,
+        ]
+    );
+    match pos_entries[0].inflection[0].inflection {
+        Some(parse_wiktionary_cs::Inflection::NounDeclensionBasic(_)) => {}
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn form_of() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===tvar podstatného jména===\n",
+        "====význam====\n",
+        "#genitiv [[jednotné číslo|jednotného čísla]] substantiva [[pes]]\n",
+        "#4. pád jednotného čísla substantiva [[pes]]\n",
+        "===tvar slovesa===\n",
+        "====význam====\n",
+        "#1. osoba jednotného čísla oznamovacího způsobu přítomného času slovesa [[psát]]\n",
+        "#nesmysl\n",
+        "#jiný nesmysl\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#pes\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(
+        output
+            .warnings
+            .iter()
+            .map(|warning| warning.code)
+            .collect::<Vec<_>>(),
+        [
+            parse_wiktionary_cs::WarningCode::InvalidFormOf,
+            parse_wiktionary_cs::WarningCode::InvalidFormOf,
+        ]
+    );
+    for (warning, text) in output.warnings.iter().zip(&["#nesmysl", "#jiný nesmysl"]) {
+        assert_eq!(&wiki_text[warning.start..warning.end], *text);
+    }
+    let pos_entries = &output.language_entries[0].pos_entries;
+    assert!(pos_entries[0].form);
+    assert!(pos_entries[1].form);
+    assert!(!pos_entries[2].form);
+    assert!(pos_entries[2].form_of.is_empty());
+    assert_eq!(pos_entries[0].pos, parse_wiktionary_cs::Pos::Noun);
+    assert_eq!(pos_entries[0].definitions.len(), 2);
+    let form_of = &pos_entries[0].form_of;
+    assert_eq!(form_of.len(), 2);
+    assert_eq!(form_of[0].lemma, "pes");
+    assert_eq!(
+        form_of[0].tags,
+        [
+            parse_wiktionary_cs::GrammaticalTag::Genitive,
+            parse_wiktionary_cs::GrammaticalTag::Singular,
+        ]
+    );
+    assert_eq!(
+        form_of[1].tags,
+        [
+            parse_wiktionary_cs::GrammaticalTag::Accusative,
+            parse_wiktionary_cs::GrammaticalTag::Singular,
+        ]
+    );
+    assert_eq!(pos_entries[1].pos, parse_wiktionary_cs::Pos::Verb);
+    let form_of = &pos_entries[1].form_of;
+    assert_eq!(form_of.len(), 1);
+    assert_eq!(form_of[0].lemma, "psát");
+    assert_eq!(
+        form_of[0].tags,
+        [
+            parse_wiktionary_cs::GrammaticalTag::FirstPerson,
+            parse_wiktionary_cs::GrammaticalTag::Singular,
+            parse_wiktionary_cs::GrammaticalTag::Indicative,
+            parse_wiktionary_cs::GrammaticalTag::Present,
+        ]
+    );
+}
+
+#[test]
+fn grammatical_details() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "* ''rod mužský životný''\n",
+        "* [[pomnožné]]\n",
+        "* rod nesmyslný\n",
+        "====význam====\n",
+        "#pes\n",
+        "===sloveso===\n",
+        "* vid dokonavý i nedokonavý\n",
+        "* přechodné\n",
+        "* rod ženský\n",
+        "* rod střední\n",
+        "====význam====\n",
+        "#jít\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(
+        output
+            .warnings
+            .iter()
+            .map(|warning| warning.code)
+            .collect::<Vec<_>>(),
+        [
+            parse_wiktionary_cs::WarningCode::UnrecognizedDetail,
+            parse_wiktionary_cs::WarningCode::ConflictingDetails,
+        ]
+    );
+    let pos_entries = &output.language_entries[0].pos_entries;
+    assert_eq!(pos_entries[0].details.len(), 3);
+    assert_eq!(
+        pos_entries[0].gender,
+        Some(parse_wiktionary_cs::Gender::Masculine)
+    );
+    assert_eq!(
+        pos_entries[0].animacy,
+        Some(parse_wiktionary_cs::Animacy::Animate)
+    );
+    assert_eq!(pos_entries[0].aspect, None);
+    assert_eq!(
+        pos_entries[1].aspect,
+        Some(parse_wiktionary_cs::Aspect::Biaspectual)
+    );
+    assert_eq!(
+        pos_entries[1].transitivity,
+        Some(parse_wiktionary_cs::Transitivity::Transitive)
+    );
+    assert_eq!(
+        pos_entries[1].gender,
+        Some(parse_wiktionary_cs::Gender::Feminine)
+    );
+}
+
+#[test]
+fn aspect() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===sloveso===\n",
+        "* ''vidový protějšek'': [[dělat]], [[činit]]\n",
+        "====časování====\n",
+        "{{Sloveso (cs)\n",
+        "|dok=ano\n",
+        "|spre1=udělám\n",
+        "}}\n",
+        "====význam====\n",
+        "#vytvořit\n",
+        "===sloveso (2)===\n",
+        "* vid nedokonavý\n",
+        "====časování====\n",
+        "{{Sloveso (cs)\n",
+        "|spre1=dělám\n",
+        "}}\n",
+        "====význam====\n",
+        "#tvořit\n",
+        "===sloveso (3)===\n",
+        "* vid obouvidový\n",
+        "====časování====\n",
+        "{{Sloveso (cs)\n",
+        "|spre1=organizuji\n",
+        "}}\n",
+        "====význam====\n",
+        "#řídit\n",
+        "===sloveso (4)===\n",
+        "====časování====\n",
+        "{{Sloveso (cs)\n",
+        "|spre1=jmenuji\n",
+        "}}\n",
+        "====význam====\n",
+        "#určit\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let pos_entries = &output.language_entries[0].pos_entries;
+    assert_eq!(
+        pos_entries[0].aspect,
+        Some(parse_wiktionary_cs::Aspect::Perfective)
+    );
+    assert_eq!(pos_entries[0].aspect_counterparts, ["dělat", "činit"]);
+    match &pos_entries[0].inflection[0].inflection {
+        Some(parse_wiktionary_cs::Inflection::Conjugation(conjugation)) => assert_eq!(
+            conjugation.aspect,
+            Some(parse_wiktionary_cs::Aspect::Perfective)
+        ),
+        _ => panic!(),
+    }
+    assert_eq!(
+        pos_entries[1].aspect,
+        Some(parse_wiktionary_cs::Aspect::Imperfective)
+    );
+    assert!(pos_entries[1].aspect_counterparts.is_empty());
+    assert_eq!(
+        pos_entries[2].aspect,
+        Some(parse_wiktionary_cs::Aspect::Biaspectual)
+    );
+    // A template without `dok=ano` doesn't tell imperfective verbs from biaspectual verbs.
+    assert_eq!(pos_entries[3].aspect, None);
+    match &pos_entries[3].inflection[0].inflection {
+        Some(parse_wiktionary_cs::Inflection::Conjugation(conjugation)) => {
+            assert_eq!(conjugation.aspect, None)
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn sub_definitions() {
+    let wiki_text = concat!(
+        "==čeština==\n",
+        "===podstatné jméno===\n",
+        "====význam====\n",
+        "#zvíře\n",
+        "#*{{Příklad|cs|Pes štěká.}}\n",
+        "## domácí zvíře\n",
+        "## divoké zvíře\n",
+        "#člověk\n"
+    );
+    let output = parse_wiktionary_cs::parse(
+        wiki_text,
+        &parse_wiktionary_cs::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let definitions = &output.language_entries[0].pos_entries[0].definitions;
+    assert_eq!(definitions.len(), 2);
+    assert_eq!(definitions[0].examples.len(), 1);
+    assert_eq!(definitions[0].children.len(), 2);
+    match definitions[0].children[1].definition.as_slice() {
+        [parse_wiktionary_cs::Flowing::Text { value }] => assert_eq!(value, "divoké zvíře"),
+        _ => panic!(),
+    }
+    assert!(definitions[1].children.is_empty());
+}
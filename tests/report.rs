@@ -0,0 +1,50 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+extern crate parse_wiktionary_cs;
+extern crate serde_json;
+
+use parse_wiktionary_cs::report::Report;
+
+fn create_report() -> Report {
+    let configuration = parse_wiktionary_cs::create_configuration();
+    let mut report = Report::new(2);
+    for (title, wiki_text) in &[
+        ("a", "==čeština==\n===sloveso===\n====význam====\n#definice\n"),
+        ("b", "==čeština==\n===sloveso===\n====nesmysl====\n"),
+        ("c", "==čeština==\n===sloveso===\n====nesmysl====\n"),
+        ("d", "==čeština==\n===sloveso===\n====nesmysl====\nx|y\n"),
+    ] {
+        let output = parse_wiktionary_cs::parse(wiki_text, &configuration.parse(wiki_text).nodes);
+        report.add(title, &output);
+    }
+    report
+}
+
+#[test]
+fn add() {
+    let report = create_report();
+    assert_eq!(report.pages, 4);
+    assert_eq!(report.pages_with_warnings, 3);
+    assert_eq!(report.warnings, 7);
+    let bucket = &report.by_message["unrecognized"];
+    assert_eq!(bucket.count, 4);
+    assert_eq!(bucket.examples, ["b", "c"]);
+    assert_eq!(report.by_message["section_empty"].count, 3);
+    assert_eq!(report.by_language["cs"].count, 7);
+    assert_eq!(report.by_section["nesmysl"].count, 4);
+    assert_eq!(report.by_section["sloveso"].count, 3);
+    assert!(report.by_template.is_empty());
+}
+
+#[test]
+fn export() {
+    let report = create_report();
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["by_message"]["unrecognized"]["count"], 4);
+    let markdown = report.to_markdown();
+    assert!(markdown.starts_with("# Warnings\n"));
+    assert!(markdown.contains("| unrecognized | 4 | b, c |\n"));
+    assert!(markdown.contains("| nesmysl | 4 | b, c |\n"));
+}